@@ -0,0 +1,279 @@
+//! Digit sets for reading and writing balanced ternary other than the
+//! standard `T`, `0` and `1`.
+//!
+//! ```
+//! use balanced_base::{BalTern, TritAlphabet};
+//!
+//! let six = BalTern::<5>::from_str_with("+-0", &TritAlphabet::SIGNS).unwrap();
+//! assert_eq!(six.to_string(), "1T0");
+//! assert_eq!(six.display_with(&TritAlphabet::ARROWS).to_string(), "↑↓0");
+//! ```
+
+use std::fmt::{self, Write};
+use super::{Bal3, ParseBalTernError};
+
+/// The digits written for `T`, `0` and `1`.
+///
+/// Digits are strings rather than chars so that combining characters such as
+/// the overline in `1̄` can be used. They must be distinct and non-empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TritAlphabet<'a> {
+    negative: &'a str,
+    zero: &'a str,
+    positive: &'a str,
+}
+
+impl TritAlphabet<'static> {
+    /// `T`, `0` and `1`, as used by `FromStr` and `Display`.
+    pub const STANDARD: Self = TritAlphabet::new("T", "0", "1");
+    /// `t`, `0` and `1`.
+    pub const LOWERCASE: Self = TritAlphabet::new("t", "0", "1");
+    /// `-`, `0` and `+`.
+    pub const SIGNS: Self = TritAlphabet::new("-", "0", "+");
+    /// `N`, `Z` and `P`, for negative, zero and positive.
+    pub const LETTERS: Self = TritAlphabet::new("N", "Z", "P");
+    /// `↓`, `0` and `↑`.
+    pub const ARROWS: Self = TritAlphabet::new("↓", "0", "↑");
+    /// `1̄`, `0` and `1`, where `1̄` is a one followed by a combining overline.
+    pub const OVERLINE: Self = TritAlphabet::new("1\u{304}", "0", "1");
+}
+
+impl<'a> TritAlphabet<'a> {
+    /// Panics if any digit is empty or two digits are the same.
+    pub const fn new(negative: &'a str, zero: &'a str, positive: &'a str) -> Self {
+        assert!(!negative.is_empty() && !zero.is_empty() && !positive.is_empty(), "digits can't be empty");
+        assert!(!same(negative, zero) && !same(zero, positive) && !same(negative, positive), "digits must be distinct");
+        TritAlphabet {negative, zero, positive}
+    }
+
+    pub fn digit(&self, trit: Bal3) -> &'a str {
+        match trit {
+            Bal3::One => self.positive,
+            Bal3::Zero => self.zero,
+            Bal3::NegativeOne => self.negative,
+        }
+    }
+
+    /// The trit `digit` stands for, which must be the whole string.
+    pub fn trit(&self, digit: &str) -> Result<Bal3, ParseBalTernError> {
+        match self.prefix(digit) {
+            Some((trit, len)) if len == digit.len() => Ok(trit),
+            Some((_, len)) => Err(Self::invalid(digit, len)),
+            None if digit.is_empty() => Err(ParseBalTernError::Empty),
+            None => Err(Self::invalid(digit, 0)),
+        }
+    }
+
+    /// Reads digits most significant first, skipping `_` separators.
+    pub(crate) fn parse(&self, s: &str) -> Result<Vec<Bal3>, ParseBalTernError> {
+        let mut trits = Vec::with_capacity(s.len());
+        let mut index = 0;
+        while index < s.len() {
+            index += match self.prefix(&s[index..]) {
+                Some((trit, len)) => {
+                    trits.push(trit);
+                    len
+                }
+                None if s[index..].starts_with('_') => 1,
+                None => return Err(Self::invalid(s, index)),
+            };
+        }
+        match trits.is_empty() {
+            true => Err(ParseBalTernError::Empty),
+            false => Ok(trits),
+        }
+    }
+
+    /// The digit `s` starts with and its length in bytes. Where one digit
+    /// starts another, as `1` starts `1̄`, the longer one wins.
+    fn prefix(&self, s: &str) -> Option<(Bal3, usize)> {
+        [Bal3::NegativeOne, Bal3::Zero, Bal3::One].into_iter()
+            .map(|trit| (trit, self.digit(trit).len()))
+            .filter(|(trit, _)| s.starts_with(self.digit(*trit)))
+            .max_by_key(|(_, len)| *len)
+    }
+
+    fn invalid(s: &str, index: usize) -> ParseBalTernError {
+        let ch = s[index..].chars().next().expect("the index should be inside the string");
+        ParseBalTernError::InvalidChar {ch, index}
+    }
+
+    /// Writes `trits` without their leading zeros, following the formatter's
+    /// flags: `{:#}` keeps every trit, `{:0}` pads with zero digits to the
+    /// width or to every trit, `{:+}` marks non-negative values with `+`,
+    /// `{:.3}` separates every 3 trits with `_` counting from the least
+    /// significant, and width, fill and alignment pad like any other number.
+    /// Zero padding that only misses the width because of a separator fills
+    /// the rest with zero digits and no separator.
+    pub(crate) fn write(&self, f: &mut fmt::Formatter<'_>, trits: &[Bal3]) -> fmt::Result {
+        let significant = trits.iter().position(|x| *x != Bal3::Zero).unwrap_or(trits.len());
+        let shown = match f.alternate() {
+            true => trits,
+            false => &trits[significant..],
+        };
+        // zero is written as a single zero digit rather than nothing
+        let mut digits: Vec<&str> = match shown.is_empty() {
+            true => vec![self.zero],
+            false => shown.iter().map(|x| self.digit(*x)).collect(),
+        };
+        let sign = match f.sign_plus() && trits.get(significant) != Some(&Bal3::NegativeOne) {
+            true => "+",
+            false => "",
+        };
+        let group = f.precision().filter(|group| *group > 0);
+        let len = |digits: &[&str]| {
+            let separators = group.map_or(0, |group| (digits.len() - 1) / group);
+            sign.chars().count() + separators + digits.iter().map(|x| x.chars().count()).sum::<usize>()
+        };
+        // zero digits written ahead of the first group without a separator
+        let mut unseparated = 0;
+        if f.sign_aware_zero_pad() {
+            // add zero digits for as long as they still fit
+            loop {
+                digits.insert(0, self.zero);
+                let fits = match f.width() {
+                    Some(width) => len(&digits) <= width,
+                    None => digits.len() <= trits.len(),
+                };
+                if !fits {
+                    digits.remove(0);
+                    break;
+                }
+            }
+            // the next zero may only have missed because of its separator
+            if let Some(width) = f.width() {
+                unseparated = (width - len(&digits).min(width)) / self.zero.chars().count();
+            }
+        }
+        let padding = f.width().unwrap_or(0).saturating_sub(len(&digits) + unseparated * self.zero.chars().count());
+        let (before, after) = match (f.sign_aware_zero_pad(), f.align()) {
+            (true, _) => (padding, 0),
+            (false, Some(fmt::Alignment::Left)) => (0, padding),
+            (false, Some(fmt::Alignment::Center)) => (padding / 2, padding - padding / 2),
+            (false, Some(fmt::Alignment::Right) | None) => (padding, 0),
+        };
+        let fill = f.fill();
+        (0..before).try_for_each(|_| f.write_char(fill))?;
+        f.write_str(sign)?;
+        (0..unseparated).try_for_each(|_| f.write_str(self.zero))?;
+        for (i, digit) in digits.iter().enumerate() {
+            if group.is_some_and(|group| i > 0 && (digits.len() - i).is_multiple_of(group)) {
+                f.write_char('_')?;
+            }
+            f.write_str(digit)?;
+        }
+        (0..after).try_for_each(|_| f.write_char(fill))
+    }
+}
+
+// `==` on strings isn't const
+const fn same(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+impl Default for TritAlphabet<'static> {
+    fn default() -> Self {
+        TritAlphabet::STANDARD
+    }
+}
+
+/// Displays a value with a `TritAlphabet`, from `display_with`.
+pub struct DisplayWith<'a, T> {
+    pub(crate) value: &'a T,
+    pub(crate) alphabet: &'a TritAlphabet<'a>,
+}
+
+impl fmt::Display for DisplayWith<'_, Bal3> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.alphabet.digit(*self.value))
+    }
+}
+
+#[cfg(test)]
+mod alphabet_tests {
+    use super::*;
+    use Bal3::{One, Zero, NegativeOne};
+
+    #[test]
+    fn presets() {
+        let trits = vec![One, NegativeOne, Zero, NegativeOne];
+        for (alphabet, written) in [
+            (TritAlphabet::STANDARD, "1T0T"),
+            (TritAlphabet::LOWERCASE, "1t0t"),
+            (TritAlphabet::SIGNS, "+-0-"),
+            (TritAlphabet::LETTERS, "PNZN"),
+            (TritAlphabet::ARROWS, "↑↓0↓"),
+            (TritAlphabet::OVERLINE, "11\u{304}01\u{304}"),
+        ] {
+            assert_eq!(alphabet.parse(written), Ok(trits.clone()));
+        }
+        assert_eq!(TritAlphabet::default(), TritAlphabet::STANDARD);
+    }
+
+    #[test]
+    fn overlapping_digits() {
+        let alphabet = TritAlphabet::OVERLINE;
+        assert_eq!(alphabet.parse("1\u{304}1\u{304}1"), Ok(vec![NegativeOne, NegativeOne, One]));
+        assert_eq!(alphabet.parse("\u{304}1"), Err(ParseBalTernError::InvalidChar {ch: '\u{304}', index: 0}));
+        let words = TritAlphabet::new("minus", "zero", "plus");
+        assert_eq!(words.parse("plusminuszero"), Ok(vec![One, NegativeOne, Zero]));
+        assert_eq!(words.parse("plusmin"), Err(ParseBalTernError::InvalidChar {ch: 'm', index: 4}));
+    }
+
+    #[test]
+    fn separators() {
+        assert_eq!(TritAlphabet::STANDARD.parse("1_T0"), Ok(vec![One, NegativeOne, Zero]));
+        assert_eq!(TritAlphabet::SIGNS.parse("_+__-_"), Ok(vec![One, NegativeOne]));
+        assert_eq!(TritAlphabet::STANDARD.parse("__"), Err(ParseBalTernError::Empty));
+        assert_eq!("1T0_01T_T10".parse::<crate::BalTern<9>>().map(|x| x.to_string()), Ok("1T001TT10".to_string()));
+    }
+
+    #[test]
+    fn single_trits() {
+        assert_eq!(TritAlphabet::ARROWS.trit("↓"), Ok(NegativeOne));
+        assert_eq!(TritAlphabet::ARROWS.digit(One), "↑");
+        assert_eq!(TritAlphabet::ARROWS.trit("↓↓"), Err(ParseBalTernError::InvalidChar {ch: '↓', index: 3}));
+        assert_eq!(TritAlphabet::ARROWS.trit("x"), Err(ParseBalTernError::InvalidChar {ch: 'x', index: 0}));
+        assert_eq!(TritAlphabet::ARROWS.trit(""), Err(ParseBalTernError::Empty));
+        assert_eq!(format!("[{:>3}]", Zero.display_with(&TritAlphabet::LETTERS)), "[  Z]");
+        assert_eq!(Bal3::from_str_with("-", &TritAlphabet::SIGNS), Ok(NegativeOne));
+    }
+
+    #[test]
+    fn numbers() {
+        use crate::{BalTern, BigBalTern};
+        let neg_forty_eight = BalTern::<6>::from_str_with("1\u{304}111\u{304}0", &TritAlphabet::OVERLINE).unwrap();
+        assert_eq!(neg_forty_eight, "T11T0".parse().unwrap());
+        assert_eq!(format!("{:#}", neg_forty_eight.display_with(&TritAlphabet::LETTERS)), "ZNPPNZ");
+        assert_eq!(format!("{:0}", BalTern::<4>::ONE.display_with(&TritAlphabet::SIGNS)), "000+");
+        assert_eq!(format!("{:+}", BalTern::<4>::ZERO.display_with(&TritAlphabet::LOWERCASE)), "+0");
+        assert_eq!(BalTern::<3>::from_str_with("tttt", &TritAlphabet::LOWERCASE), Err(ParseBalTernError::NegOverflow {width: 3, value: BigBalTern::from(-40)}));
+        let big = BigBalTern::from_str_with("↑↓↓0↓", &TritAlphabet::ARROWS).unwrap();
+        assert_eq!(big, BigBalTern::from(44));
+        assert_eq!(format!("{:>6}", big.display_with(&TritAlphabet::SIGNS)), " +--0-");
+        assert_eq!(BigBalTern::default().display_with(&TritAlphabet::LETTERS).to_string(), "Z");
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_digit() {
+        let _ = TritAlphabet::new("T", "", "1");
+    }
+
+    #[test]
+    #[should_panic]
+    fn repeated_digit() {
+        let _ = TritAlphabet::new("1", "1", "x");
+    }
+}
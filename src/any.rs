@@ -0,0 +1,310 @@
+//! `AnyBalTern`, which picks its width at runtime from the enabled width
+//! aliases.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Sub, Mul, Div, Rem, Neg};
+use std::str::FromStr;
+use super::{BalTern, BigBalTern, TryFromIntError, Width, ParseBalTernError, ArithmeticError};
+
+macro_rules! any_bal_tern {
+    ($(($width:literal, $feature:literal, $alias:ident)),*) => {
+        /// A balanced ternary integer held in whichever enabled width alias
+        /// fits it.
+        ///
+        /// Parsing and converting from integers pick the narrowest width that
+        /// fits. Arithmetic promotes: the result is at least as wide as the
+        /// wider operand, and wider still if it needs to be.
+        #[derive(Debug, Clone, Copy)]
+        pub enum AnyBalTern {
+            $(
+                #[cfg(feature = $feature)]
+                $alias(BalTern<$width>),
+            )*
+        }
+
+        impl AnyBalTern {
+            /// The number of trits in the widest enabled width.
+            fn widest() -> usize {
+                let widest = 0;
+                $(
+                    #[cfg(feature = $feature)]
+                    let widest = widest.max($width);
+                )*
+                widest
+            }
+
+            /// The number of trits in the current width.
+            pub fn width(&self) -> usize {
+                match *self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        AnyBalTern::$alias(_) => $width,
+                    )*
+                }
+            }
+
+            /// The narrowest width of at least `min_width` trits that holds
+            /// `value`, if any does.
+            fn fit(value: &BigBalTern, min_width: usize) -> Option<Self> {
+                $(
+                    #[cfg(feature = $feature)]
+                    if $width >= min_width {
+                        if let Ok(fitted) = BalTern::<$width>::try_from(value.clone()) {
+                            return Some(AnyBalTern::$alias(fitted));
+                        }
+                    }
+                )*
+                None
+            }
+        }
+
+        impl fmt::Display for AnyBalTern {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        AnyBalTern::$alias(inner) => fmt::Display::fmt(inner, f),
+                    )*
+                }
+            }
+        }
+
+        impl From<AnyBalTern> for BigBalTern {
+            fn from(input: AnyBalTern) -> Self {
+                match input {
+                    $(
+                        #[cfg(feature = $feature)]
+                        AnyBalTern::$alias(inner) => inner.into(),
+                    )*
+                }
+            }
+        }
+
+        $(
+            #[cfg(feature = $feature)]
+            impl From<BalTern<$width>> for AnyBalTern {
+                fn from(input: BalTern<$width>) -> Self {
+                    AnyBalTern::$alias(input)
+                }
+            }
+        )*
+    };
+}
+
+any_bal_tern!(
+    (3, "t3", T3), (5, "t5", T5), (6, "t6", T6), (9, "t9", T9), (10, "t10", T10),
+    (18, "t18", T18), (20, "t20", T20), (27, "t27", T27), (40, "t40", T40), (80, "t80", T80)
+);
+
+impl AnyBalTern {
+    /// Computes exactly, then fits the result to a width no narrower than
+    /// either operand.
+    fn promote(self, rhs: Self, op: impl FnOnce(BigBalTern, BigBalTern) -> BigBalTern) -> Option<Self> {
+        let width = self.width().max(rhs.width());
+        Self::fit(&op(self.into(), rhs.into()), width)
+    }
+
+    /// `None` only if the result is too wide for every enabled width.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.promote(rhs, |a, b| a + b)
+    }
+
+    /// `None` only if the result is too wide for every enabled width.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.promote(rhs, |a, b| a - b)
+    }
+
+    /// `None` only if the result is too wide for every enabled width.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.promote(rhs, |a, b| a * b)
+    }
+
+    /// Quotient rounded towards zero and the remainder, which takes the sign
+    /// of `self`. Neither is ever wider than the operands.
+    pub fn div_rem(self, rhs: Self) -> Result<(Self, Self), ArithmeticError> {
+        let width = self.width().max(rhs.width());
+        let (quot, rem) = BigBalTern::from(self).div_rem(rhs.into())?;
+        let fit = |value| Self::fit(&value, width).expect("division never grows the operands");
+        Ok((fit(quot), fit(rem)))
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.div_rem(rhs).ok().map(|(quot, _)| quot)
+    }
+
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.div_rem(rhs).ok().map(|(_, rem)| rem)
+    }
+}
+
+/// Equality, ordering and hashing only see the value, so the same number in
+/// two widths is equal.
+impl PartialEq for AnyBalTern {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for AnyBalTern {}
+
+impl Ord for AnyBalTern {
+    fn cmp(&self, other: &Self) -> Ordering {
+        BigBalTern::from(*self).cmp(&BigBalTern::from(*other))
+    }
+}
+
+impl PartialOrd for AnyBalTern {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for AnyBalTern {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        BigBalTern::from(*self).hash(state);
+    }
+}
+
+impl FromStr for AnyBalTern {
+    type Err = ParseBalTernError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value: BigBalTern = s.parse()?;
+        AnyBalTern::fit(&value, 0).ok_or_else(|| ParseBalTernError::overflow(AnyBalTern::widest(), value))
+    }
+}
+
+macro_rules! impl_try_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl TryFrom<$int> for AnyBalTern {
+                type Error = TryFromIntError;
+
+                fn try_from(int: $int) -> Result<Self, Self::Error> {
+                    let value = BigBalTern::from(int);
+                    AnyBalTern::fit(&value, 0).ok_or_else(|| TryFromIntError::overflow(Width::Trits(AnyBalTern::widest()), value))
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<const N: usize> TryFrom<AnyBalTern> for BalTern<N> {
+    type Error = TryFromIntError;
+
+    fn try_from(input: AnyBalTern) -> Result<Self, Self::Error> {
+        BalTern::try_from(BigBalTern::from(input))
+    }
+}
+
+impl Add for AnyBalTern {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("Integer Overflow")
+    }
+}
+
+impl Sub for AnyBalTern {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect("Integer Underflow")
+    }
+}
+
+impl Mul for AnyBalTern {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).expect("Out of Bounds")
+    }
+}
+
+/// Panics if `rhs` is zero. Use `div_rem` to get
+/// `ArithmeticError::DivisionByZero` instead, or `checked_div`.
+impl Div for AnyBalTern {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(rhs).expect("Division by Zero")
+    }
+}
+
+/// Panics if `rhs` is zero. Use `div_rem` to get
+/// `ArithmeticError::DivisionByZero` instead, or `checked_rem`.
+impl Rem for AnyBalTern {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.checked_rem(rhs).expect("Division by Zero")
+    }
+}
+
+/// Negation never changes the width.
+impl Neg for AnyBalTern {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        AnyBalTern::fit(&-BigBalTern::from(self), self.width()).expect("negation never overflows")
+    }
+}
+
+#[cfg(all(test, feature = "t3", feature = "t5", feature = "t9", feature = "t10", feature = "t20", feature = "t27", feature = "t40", feature = "t80"))]
+mod any_tests {
+    use super::*;
+    use crate::{T3, T5, T10, T20, T40, T80};
+
+    #[test]
+    fn narrowest_width() {
+        assert_eq!("1T".parse::<AnyBalTern>(), Ok(AnyBalTern::T3(T3::try_from(2).unwrap())));
+        assert_eq!("0001T".parse::<AnyBalTern>().map(|x| x.width()), Ok(3));
+        assert_eq!("1T0T".parse::<AnyBalTern>().map(|x| x.width()), Ok(5));
+        assert_eq!(AnyBalTern::try_from(122).map(|x| x.width()), Ok(6));
+        assert_eq!(AnyBalTern::try_from(i32::MIN).map(|x| x.width()), Ok(27));
+        assert_eq!(AnyBalTern::try_from(u128::MAX), Err(TryFromIntError::PosOverflow {width: Width::Trits(80), value: BigBalTern::from(u128::MAX)}));
+        assert_eq!(AnyBalTern::try_from(i128::MIN), Err(TryFromIntError::NegOverflow {width: Width::Trits(80), value: BigBalTern::from(i128::MIN)}));
+        assert_eq!("1".repeat(81).parse::<AnyBalTern>(), Err(ParseBalTernError::PosOverflow {width: 80, value: "1".repeat(81).parse().unwrap()}));
+        assert_eq!("12".parse::<AnyBalTern>(), Err(ParseBalTernError::InvalidChar {ch: '2', index: 1}));
+    }
+
+    #[test]
+    fn promotion() {
+        let small = AnyBalTern::try_from(13).unwrap();
+        let five = AnyBalTern::from(T5::try_from(1).unwrap());
+        assert_eq!(small.width(), 3);
+        assert_eq!(small + five, AnyBalTern::T5(T5::try_from(14).unwrap()));
+        assert_eq!((small + five).width(), 5);
+        assert_eq!((small + small).width(), 5);
+        assert_eq!((small * small * small).to_string(), "10000101");
+        assert_eq!(small * AnyBalTern::from(T40::MAX_VALUE) * small, AnyBalTern::T80(T80::from(T40::MAX_VALUE) * T80::try_from(169).unwrap()));
+        assert_eq!(AnyBalTern::from(T80::MAX_VALUE).checked_add(small), None);
+        assert_eq!(-AnyBalTern::from(T10::ONE), AnyBalTern::T10(T10::NEG_ONE));
+        assert_eq!((-AnyBalTern::from(T10::ONE)).width(), 10);
+        assert_eq!(T20::try_from(small * small), Ok(T20::try_from(169).unwrap()));
+    }
+
+    #[test]
+    fn value_equality() {
+        use std::collections::HashSet;
+        let a = AnyBalTern::from(T3::ONE);
+        let b = AnyBalTern::from(T5::ONE);
+        assert_eq!(a, b);
+        assert_eq!((a + b) - b, a);
+        assert!(AnyBalTern::from(T80::NEG_ONE) < a);
+        assert_eq!(HashSet::from([a, b, AnyBalTern::from(T40::ONE)]).len(), 1);
+    }
+
+    #[test]
+    fn division() {
+        let big = AnyBalTern::try_from(-1000).unwrap();
+        let small = AnyBalTern::try_from(7).unwrap();
+        assert_eq!(big.div_rem(small), Ok((AnyBalTern::T9(BalTern::try_from(-142).unwrap()), AnyBalTern::T9(BalTern::try_from(-6).unwrap()))));
+        assert_eq!(big.checked_div(AnyBalTern::from(T3::ZERO)), None);
+        assert_eq!((big / small).width(), 9);
+    }
+}
@@ -0,0 +1,155 @@
+//! Trit-level arithmetic on most-significant-first slices of `Bal3`.
+//!
+//! These work on slices rather than `BalTern<N>` so that products and
+//! quotients can use scratch space wider than either operand.
+
+use std::cmp::Ordering;
+use super::Bal3;
+
+/// Adds `rhs` into `acc`, lining both up at their least significant trit,
+/// and returns the carry out of the top of `acc`.
+pub(crate) fn add_into(acc: &mut [Bal3], rhs: &[Bal3], carry: Bal3) -> Bal3 {
+    debug_assert!(rhs.len() <= acc.len());
+    let mut carry = carry;
+    let mut rhs = rhs.iter().rev();
+    for trit in acc.iter_mut().rev() {
+        let other = match rhs.next() {
+            Some(other) => *other,
+            None if carry == Bal3::Zero => break,
+            None => Bal3::Zero,
+        };
+        (*trit, carry) = trit.full_add(other, carry);
+    }
+    carry
+}
+
+/// Subtracts `rhs` from `acc` by adding its negation, returning the borrow.
+pub(crate) fn sub_into(acc: &mut [Bal3], rhs: &[Bal3], borrow: Bal3) -> Bal3 {
+    debug_assert!(rhs.len() <= acc.len());
+    let mut carry = -borrow;
+    let mut rhs = rhs.iter().rev();
+    for trit in acc.iter_mut().rev() {
+        let other = match rhs.next() {
+            Some(other) => -*other,
+            None if carry == Bal3::Zero => break,
+            None => Bal3::Zero,
+        };
+        (*trit, carry) = trit.full_add(other, carry);
+    }
+    -carry
+}
+
+/// Negating a balanced ternary number is just flipping every trit.
+pub(crate) fn negate(trits: &mut [Bal3]) {
+    for trit in trits.iter_mut() {
+        *trit = -*trit;
+    }
+}
+
+/// The sign of a number is the sign of its most significant non-zero trit.
+pub(crate) fn signum(trits: &[Bal3]) -> Bal3 {
+    trits.iter().copied().find(|x| *x != Bal3::Zero).unwrap_or(Bal3::Zero)
+}
+
+/// Compares `a` with `b * 3^shift`, treating missing high trits as zero.
+/// Balanced ternary orders like its digits, so the first trit that differs
+/// from the top decides.
+pub(crate) fn cmp_shifted(a: &[Bal3], b: &[Bal3], shift: usize) -> Ordering {
+    let trit_at = |s: &[Bal3], pos: usize| if pos < s.len() { s[s.len() - 1 - pos] } else { Bal3::Zero };
+    let top = a.len().max(b.len() + shift);
+    for pos in (0..top).rev() {
+        let x = trit_at(a, pos);
+        let y = if pos >= shift { trit_at(b, pos - shift) } else { Bal3::Zero };
+        match x.cmp(&y) {
+            Ordering::Equal => continue,
+            other => return other,
+        }
+    }
+    Ordering::Equal
+}
+
+/// Shift-and-add multiplication. `out` must be zeroed and at least
+/// `a.len() + b.len()` trits long, which is enough to never overflow.
+pub(crate) fn mul_into(out: &mut [Bal3], a: &[Bal3], b: &[Bal3]) {
+    debug_assert!(out.len() >= a.len() + b.len());
+    let len = out.len();
+    for (shift, trit) in b.iter().rev().enumerate() {
+        // ending the slice early lines a's lowest trit up with 3^shift
+        match trit {
+            Bal3::One => { add_into(&mut out[..len - shift], a, Bal3::Zero); },
+            Bal3::NegativeOne => { sub_into(&mut out[..len - shift], a, Bal3::Zero); },
+            Bal3::Zero => (),
+        }
+    }
+}
+
+/// Long division of magnitudes. `rem` holds the non-negative dividend and is
+/// left holding the remainder, `quot` must be zeroed and as long as `rem`,
+/// and `divisor` must be positive.
+pub(crate) fn div_rem_magnitude(rem: &mut [Bal3], divisor: &[Bal3], quot: &mut [Bal3]) {
+    debug_assert_eq!(rem.len(), quot.len());
+    let start = divisor.iter().position(|x| *x != Bal3::Zero).expect("divisor should be positive");
+    let divisor = &divisor[start..];
+    let len = rem.len();
+    if divisor.len() > len {
+        return;
+    }
+    for shift in (0..=len - divisor.len()).rev() {
+        // the remainder is below divisor * 3^(shift + 1), so at most two fit
+        for _ in 0..2 {
+            if cmp_shifted(rem, divisor, shift) == Ordering::Less {
+                break;
+            }
+            sub_into(&mut rem[..len - shift], divisor, Bal3::Zero);
+            add_into(&mut quot[..len - shift], &[Bal3::One], Bal3::Zero);
+        }
+    }
+}
+
+#[cfg(test)]
+mod arith_tests {
+    use super::*;
+    use Bal3::{One, Zero, NegativeOne};
+
+    #[test]
+    fn ripple_carry() {
+        // 1T (2) + 11 (4) = 1T0 (6)
+        let mut acc = [Zero, One, NegativeOne];
+        assert_eq!(add_into(&mut acc, &[One, One], Zero), Zero);
+        assert_eq!(acc, [One, NegativeOne, Zero]);
+        // 11 (4) + 11 (4) = 10T (8), carried out of a two trit accumulator
+        let mut acc = [One, One];
+        assert_eq!(add_into(&mut acc, &[One, One], Zero), One);
+        assert_eq!(acc, [Zero, NegativeOne]);
+    }
+
+    #[test]
+    fn borrow() {
+        // 1T (2) - 11 (4) = T1 (-2)
+        let mut acc = [One, NegativeOne];
+        assert_eq!(sub_into(&mut acc, &[One, One], Zero), Zero);
+        assert_eq!(acc, [NegativeOne, One]);
+        // TT (-4) - 11 (4) = T01 (-8), borrowed out of a two trit accumulator
+        let mut acc = [NegativeOne, NegativeOne];
+        assert_eq!(sub_into(&mut acc, &[One, One], Zero), One);
+        assert_eq!(acc, [Zero, One]);
+    }
+
+    #[test]
+    fn shift_and_add() {
+        // T01 (-8) * 1T (2) = T11T (-16)
+        let mut out = [Zero; 5];
+        mul_into(&mut out, &[NegativeOne, Zero, One], &[One, NegativeOne]);
+        assert_eq!(out, [Zero, NegativeOne, One, One, NegativeOne]);
+    }
+
+    #[test]
+    fn long_division() {
+        // 1T00 (18) / 11 (4) = 11 (4) remainder 1T (2)
+        let mut rem = [One, NegativeOne, Zero, Zero];
+        let mut quot = [Zero; 4];
+        div_rem_magnitude(&mut rem, &[Zero, One, One], &mut quot);
+        assert_eq!(quot, [Zero, Zero, One, One]);
+        assert_eq!(rem, [Zero, Zero, One, NegativeOne]);
+    }
+}
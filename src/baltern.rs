@@ -0,0 +1,867 @@
+use std::fmt;
+use std::cmp::Ordering;
+use std::ops::{Add, Sub, Neg, Mul, Div, Rem, BitAnd, BitOr, Not, Shl, Shr};
+use std::str::FromStr;
+use super::{Bal3, BigBalTern, TritAlphabet, DisplayWith, TryFromIntError, Width, ParseBalTernError, ArithmeticError, arith, literal};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BalTern<const N: usize> {
+    // most significant trit first
+    pub(crate) value: [Bal3; N]
+}
+
+impl<const N: usize> BalTern<N> {
+    pub const ZERO: Self = BalTern {value: [Bal3::Zero; N]};
+    pub const ONE: Self = Self::from_lowest_trit(Bal3::One);
+    pub const NEG_ONE: Self = Self::from_lowest_trit(Bal3::NegativeOne);
+    /// Every trit `1`, the typed counterpart of the primitive `MAX`.
+    pub const MAX_VALUE: Self = BalTern {value: [Bal3::One; N]};
+    /// Every trit `T`, the typed counterpart of the primitive `MIN`.
+    pub const MIN_VALUE: Self = BalTern {value: [Bal3::NegativeOne; N]};
+
+    const fn from_lowest_trit(trit: Bal3) -> Self {
+        let mut value = [Bal3::Zero; N];
+        value[N - 1] = trit;
+        BalTern {value}
+    }
+
+    /// Moves the value into a width of `M` trits, padding with `Bal3::Zero`
+    /// when widening and failing if narrowing would drop significant trits.
+    pub fn try_resize<const M: usize>(self) -> Result<BalTern<M>, TryFromIntError> {
+        let mut value = [Bal3::Zero; M];
+        if M >= N {
+            value[M - N..].copy_from_slice(&self.value);
+        } else {
+            if arith::signum(&self.value[..N - M]) != Bal3::Zero {
+                return Err(TryFromIntError::overflow(Width::Trits(M), self.into()));
+            }
+            value.copy_from_slice(&self.value[N - M..]);
+        }
+        Ok(BalTern {value})
+    }
+
+    /// Builds a value from an `i128`, the shared path for every primitive conversion.
+    pub(crate) fn try_from_i128(int: i128) -> Result<Self, TryFromIntError> {
+        let mut value = [Bal3::Zero; N];
+        let mut remainder = int;
+        for trit in value.iter_mut().rev() {
+            // 2 (mod 3) is written as 1T, so carry one into the next trit
+            *trit = match remainder.rem_euclid(3) {
+                0 => Bal3::Zero,
+                1 => Bal3::One,
+                _ => Bal3::NegativeOne,
+            };
+            remainder = match trit {
+                Bal3::NegativeOne => remainder.div_euclid(3) + 1,
+                _ => remainder.div_euclid(3),
+            };
+        }
+        match remainder {
+            0 => Ok(BalTern {value}),
+            _ => Err(TryFromIntError::overflow(Width::Trits(N), BigBalTern::from(int))),
+        }
+    }
+
+    /// Same as `try_from_i128`, for the values an `i128` can't hold.
+    pub(crate) fn try_from_u128(int: u128) -> Result<Self, TryFromIntError> {
+        if let Ok(int) = i128::try_from(int) {
+            return Self::try_from_i128(int);
+        }
+        let mut value = [Bal3::Zero; N];
+        let mut remainder = int;
+        for trit in value.iter_mut().rev() {
+            *trit = match remainder % 3 {
+                0 => Bal3::Zero,
+                1 => Bal3::One,
+                _ => Bal3::NegativeOne,
+            };
+            remainder = remainder / 3 + u128::from(*trit == Bal3::NegativeOne);
+        }
+        match remainder {
+            0 => Ok(BalTern {value}),
+            _ => Err(TryFromIntError::overflow(Width::Trits(N), BigBalTern::from(int))),
+        }
+    }
+
+    /// Only meaningful for widths of up to 80 trits.
+    #[allow(dead_code)]
+    pub(crate) fn to_i128(self) -> i128 {
+        self.value.iter().fold(0, |acc, x| match x {
+            Bal3::One => acc * 3 + 1,
+            Bal3::Zero => acc * 3,
+            Bal3::NegativeOne => acc * 3 - 1,
+        })
+    }
+}
+
+impl<const N: usize> BalTern<N> {
+    /// Adds `rhs`, returning the wrapped result and the trit carried out of
+    /// the top. The carry is `Bal3::Zero` unless the addition overflowed.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, Bal3) {
+        let mut value = self.value;
+        let carry = arith::add_into(&mut value, &rhs.value, Bal3::Zero);
+        (BalTern {value}, carry)
+    }
+
+    /// Subtracts `rhs`, returning the wrapped result and, like
+    /// `overflowing_add`, the sign of the overflow: `Bal3::NegativeOne` past
+    /// `MIN`. This is the negation of the borrow from `sub_with_borrow`.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, Bal3) {
+        let mut value = self.value;
+        let borrow = arith::sub_into(&mut value, &rhs.value, Bal3::Zero);
+        (BalTern {value}, -borrow)
+    }
+
+    /// Multiplies by `rhs`, returning the low N trits of the product and the
+    /// sign of the trits that did not fit.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, Bal3) {
+        // high word first, so flattened this is one 2N trit number
+        let mut product = [[Bal3::Zero; N]; 2];
+        arith::mul_into(product.as_flattened_mut(), &self.value, &rhs.value);
+        let [high, low] = product;
+        (BalTern {value: low}, arith::signum(&high))
+    }
+
+    /// Balanced ternary is symmetric, so negation can never overflow.
+    pub fn overflowing_neg(self) -> (Self, Bal3) {
+        (-self, Bal3::Zero)
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (value, Bal3::Zero) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (value, Bal3::Zero) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (value, Bal3::Zero) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(-self)
+    }
+
+    /// Addition modulo 3^N: the trits that don't fit are dropped.
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Subtraction modulo 3^N: the trits that don't fit are dropped.
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Multiplication modulo 3^N: the trits that don't fit are dropped.
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+
+    pub fn wrapping_neg(self) -> Self {
+        -self
+    }
+
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self::saturate(self.overflowing_add(rhs))
+    }
+
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self::saturate(self.overflowing_sub(rhs))
+    }
+
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        Self::saturate(self.overflowing_mul(rhs))
+    }
+
+    pub fn saturating_neg(self) -> Self {
+        -self
+    }
+
+    fn saturate((value, overflow): (Self, Bal3)) -> Self {
+        match overflow {
+            Bal3::Zero => value,
+            Bal3::One => Self::MAX_VALUE,
+            Bal3::NegativeOne => Self::MIN_VALUE,
+        }
+    }
+}
+
+/// Building blocks for numbers wider than one word, chained from the least
+/// significant word up.
+impl<const N: usize> BalTern<N> {
+    /// Adds `rhs` and an incoming `carry`, returning the wrapped sum and the
+    /// carry out of the top, so `self + rhs + carry = sum + carry_out * 3^N`.
+    pub fn add_with_carry(self, rhs: Self, carry: Bal3) -> (Self, Bal3) {
+        let mut value = self.value;
+        let carry = arith::add_into(&mut value, &rhs.value, carry);
+        (BalTern {value}, carry)
+    }
+
+    /// Subtracts `rhs` and an incoming `borrow`, returning the wrapped
+    /// difference and the borrow from above the top, so
+    /// `self - rhs - borrow = difference - borrow_out * 3^N`.
+    pub fn sub_with_borrow(self, rhs: Self, borrow: Bal3) -> (Self, Bal3) {
+        let mut value = self.value;
+        let borrow = arith::sub_into(&mut value, &rhs.value, borrow);
+        (BalTern {value}, borrow)
+    }
+
+    /// The whole 2N trit product as `(high, low)` words, so
+    /// `self * rhs = high * 3^N + low`. This can never overflow.
+    pub fn full_mul(self, rhs: Self) -> (Self, Self) {
+        let mut product = [[Bal3::Zero; N]; 2];
+        arith::mul_into(product.as_flattened_mut(), &self.value, &rhs.value);
+        let [high, low] = product;
+        (BalTern {value: high}, BalTern {value: low})
+    }
+}
+
+impl<const N: usize> BalTern<N> {
+    /// Quotient rounded towards zero and the remainder, which takes the sign
+    /// of `self`. This is what `/` and `%` do.
+    pub fn div_rem(self, rhs: Self) -> Result<(Self, Self), ArithmeticError> {
+        let (self_sign, rhs_sign) = (arith::signum(&self.value), arith::signum(&rhs.value));
+        if rhs_sign == Bal3::Zero {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        let (mut rem, mut divisor) = (self.value, rhs.value);
+        if self_sign == Bal3::NegativeOne {
+            arith::negate(&mut rem);
+        }
+        if rhs_sign == Bal3::NegativeOne {
+            arith::negate(&mut divisor);
+        }
+        let mut quot = [Bal3::Zero; N];
+        arith::div_rem_magnitude(&mut rem, &divisor, &mut quot);
+        if self_sign != rhs_sign {
+            arith::negate(&mut quot);
+        }
+        if self_sign == Bal3::NegativeOne {
+            arith::negate(&mut rem);
+        }
+        Ok((BalTern {value: quot}, BalTern {value: rem}))
+    }
+
+    /// Quotient and remainder with the remainder always in `0..|rhs|`.
+    pub fn div_rem_euclid(self, rhs: Self) -> Result<(Self, Self), ArithmeticError> {
+        let (quot, rem) = self.div_rem(rhs)?;
+        if arith::signum(&rem.value) != Bal3::NegativeOne {
+            Ok((quot, rem))
+        } else if arith::signum(&rhs.value) == Bal3::One {
+            Ok((quot.wrapping_sub(Self::ONE), rem.wrapping_add(rhs)))
+        } else {
+            Ok((quot.wrapping_add(Self::ONE), rem.wrapping_sub(rhs)))
+        }
+    }
+
+    /// Quotient rounded towards negative infinity and the remainder, which
+    /// takes the sign of `rhs`.
+    pub fn div_rem_floor(self, rhs: Self) -> Result<(Self, Self), ArithmeticError> {
+        let (quot, rem) = self.div_rem(rhs)?;
+        match arith::signum(&rem.value) {
+            Bal3::Zero => Ok((quot, rem)),
+            sign if sign == arith::signum(&rhs.value) => Ok((quot, rem)),
+            _ => Ok((quot.wrapping_sub(Self::ONE), rem.wrapping_add(rhs))),
+        }
+    }
+
+    /// Quotient rounded to the nearest integer and the remainder, which lies
+    /// in `[-|rhs|/2, |rhs|/2]`. Truncating a balanced ternary number rounds it
+    /// to nearest, so this is the natural division for the representation.
+    /// Exact ties keep the truncated quotient.
+    pub fn div_rem_balanced(self, rhs: Self) -> Result<(Self, Self), ArithmeticError> {
+        let (quot, rem) = self.div_rem(rhs)?;
+        let abs_rem = rem.abs();
+        if abs_rem <= rhs.abs().wrapping_sub(abs_rem) {
+            Ok((quot, rem))
+        } else if arith::signum(&rem.value) == arith::signum(&rhs.value) {
+            Ok((quot.wrapping_add(Self::ONE), rem.wrapping_sub(rhs)))
+        } else {
+            Ok((quot.wrapping_sub(Self::ONE), rem.wrapping_add(rhs)))
+        }
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.div_rem(rhs).ok().map(|(quot, _)| quot)
+    }
+
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.div_rem(rhs).ok().map(|(_, rem)| rem)
+    }
+
+    pub fn div_euclid(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        self.div_rem_euclid(rhs).map(|(quot, _)| quot)
+    }
+
+    pub fn rem_euclid(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        self.div_rem_euclid(rhs).map(|(_, rem)| rem)
+    }
+
+    pub fn div_floor(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        self.div_rem_floor(rhs).map(|(quot, _)| quot)
+    }
+
+    pub fn div_balanced(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        self.div_rem_balanced(rhs).map(|(quot, _)| quot)
+    }
+
+    pub fn rem_balanced(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        self.div_rem_balanced(rhs).map(|(_, rem)| rem)
+    }
+
+    fn abs(self) -> Self {
+        match arith::signum(&self.value) {
+            Bal3::NegativeOne => -self,
+            _ => self,
+        }
+    }
+}
+
+impl<const N: usize> BalTern<N> {
+    /// Tritwise minimum, the ternary AND. Also available as `&`.
+    pub fn tritwise_min(self, rhs: Self) -> Self {
+        self.tritwise(rhs, |a, b| a.min(b))
+    }
+
+    /// Tritwise maximum, the ternary OR. Also available as `|`.
+    pub fn tritwise_max(self, rhs: Self) -> Self {
+        self.tritwise(rhs, |a, b| a.max(b))
+    }
+
+    /// Tritwise product, the ternary analogue of XNOR: `1` where the trits
+    /// agree, `T` where they are opposite and `0` where either is `0`.
+    pub fn tritwise_mul(self, rhs: Self) -> Self {
+        self.tritwise(rhs, |a, b| a * b)
+    }
+
+    /// Keeps the trits both sides agree on and zeroes the rest.
+    pub fn consensus(self, rhs: Self) -> Self {
+        self.tritwise(rhs, |a, b| if a == b { a } else { 0 })
+    }
+
+    /// Takes whichever trit is non-zero, or `0` when the two conflict.
+    pub fn any(self, rhs: Self) -> Self {
+        self.tritwise(rhs, |a, b| a + b)
+    }
+
+    fn tritwise(self, rhs: Self, op: impl Fn(i8, i8) -> i8) -> Self {
+        let mut value = self.value;
+        for (trit, other) in value.iter_mut().zip(rhs.value) {
+            *trit = Bal3::from_sign(op(i8::from(*trit), i8::from(other)));
+        }
+        BalTern {value}
+    }
+}
+
+impl<const N: usize> BalTern<N> {
+    /// Multiplies by 3^`shift` by moving every trit up, returning whether any
+    /// non-zero trits fell off the top.
+    pub fn overflowing_shl_trits(self, shift: u32) -> (Self, bool) {
+        let shift = (shift as usize).min(N);
+        let mut value = [Bal3::Zero; N];
+        value[..N - shift].copy_from_slice(&self.value[shift..]);
+        (BalTern {value}, self.value[..shift].iter().any(|x| *x != Bal3::Zero))
+    }
+
+    /// Divides by 3^`shift` by moving every trit down, returning whether any
+    /// non-zero trits fell off the bottom. Dropping the bottom trits of a
+    /// balanced ternary number rounds it to the nearest multiple, so this is
+    /// round-to-nearest division, not floor.
+    pub fn overflowing_shr_trits(self, shift: u32) -> (Self, bool) {
+        let shift = (shift as usize).min(N);
+        let mut value = [Bal3::Zero; N];
+        value[shift..].copy_from_slice(&self.value[..N - shift]);
+        (BalTern {value}, self.value[N - shift..].iter().any(|x| *x != Bal3::Zero))
+    }
+
+    pub fn shl_trits(self, shift: u32) -> Self {
+        self.overflowing_shl_trits(shift).0
+    }
+
+    pub fn shr_trits(self, shift: u32) -> Self {
+        self.overflowing_shr_trits(shift).0
+    }
+
+    pub fn rotate_left_trits(self, shift: u32) -> Self {
+        let mut value = self.value;
+        if N > 0 {
+            value.rotate_left(shift as usize % N);
+        }
+        BalTern {value}
+    }
+
+    pub fn rotate_right_trits(self, shift: u32) -> Self {
+        let mut value = self.value;
+        if N > 0 {
+            value.rotate_right(shift as usize % N);
+        }
+        BalTern {value}
+    }
+}
+
+impl<const N: usize> Default for BalTern<N> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize> Ord for BalTern<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<const N: usize> PartialOrd for BalTern<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The trits and the value in decimal, `T10(1T0 = 6)`. The decimal is left
+/// out if it doesn't fit in an `i128`.
+impl<const N: usize> fmt::Debug for BalTern<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "T{}({}", N, self)?;
+        if let Ok(int) = i128::try_from(BigBalTern::from(*self)) {
+            write!(f, " = {}", int)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Leading zeros are dropped, so zero prints as `0`. The usual flags work:
+/// `{:#}` prints all N trits, `{:0}` pads with zero trits to N (or to the
+/// width if one is given), `{:+}` marks non-negative values with `+`, and
+/// width, fill and alignment pad like any other number.
+impl<const N: usize> fmt::Display for BalTern<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        TritAlphabet::STANDARD.write(f, &self.value)
+    }
+}
+
+impl<const N: usize> fmt::Display for DisplayWith<'_, BalTern<N>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.alphabet.write(f, &self.value.value)
+    }
+}
+
+impl<const N: usize> BalTern<N> {
+    /// Parses digits of `alphabet`, most significant first.
+    pub fn from_str_with(s: &str, alphabet: &TritAlphabet) -> Result<Self, ParseBalTernError> {
+        Self::from_parsed(alphabet.parse(s)?)
+    }
+
+    /// Leading zeros don't count towards the width, so zero-padded input of
+    /// any length is fine and overflow takes the sign of the first nonzero.
+    fn from_parsed(trits: Vec<Bal3>) -> Result<Self, ParseBalTernError> {
+        let start = trits.iter().position(|x| *x != Bal3::Zero).unwrap_or(trits.len());
+        let trits = &trits[start..];
+        if trits.len() > N {
+            return Err(ParseBalTernError::overflow(N, BigBalTern::from_trits(trits.to_vec())));
+        }
+        let mut value = [Bal3::Zero; N];
+        value[N - trits.len()..].copy_from_slice(trits);
+        Ok(BalTern {value})
+    }
+
+    /// Formats with `alphabet` in place of `T`, `0` and `1`.
+    pub fn display_with<'a>(&'a self, alphabet: &'a TritAlphabet<'a>) -> DisplayWith<'a, Self> {
+        DisplayWith {value: self, alphabet}
+    }
+}
+
+impl<const N: usize> FromStr for BalTern<N> {
+    type Err = ParseBalTernError;
+
+    /// Reads balanced ternary, optionally prefixed with `0t`, or a `0d`
+    /// decimal, `0n` balanced nonary (digits `WXYZ01234` for -4 to 4) or
+    /// `0x` hexadecimal literal. Decimal and hexadecimal may be signed after
+    /// the prefix, as in `0d-48`, and `_` can separate digits anywhere.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_parsed(literal::parse(s)?)
+    }
+}
+
+impl<const N: usize> From<BalTern<N>> for String {
+    fn from(input: BalTern<N>) -> Self {
+        input.to_string()
+    }
+}
+
+impl<const N: usize> Add for BalTern<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("Integer Overflow")
+    }
+}
+
+impl<const N: usize> Sub for BalTern<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect("Integer Underflow")
+    }
+}
+
+impl<const N: usize> Mul for BalTern<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).expect("Out of Bounds")
+    }
+}
+
+/// Panics if `rhs` is zero. Use `div_rem` to get
+/// `ArithmeticError::DivisionByZero` instead, or `checked_div`.
+impl<const N: usize> Div for BalTern<N> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(rhs).expect("Division by Zero")
+    }
+}
+
+/// Panics if `rhs` is zero. Use `div_rem` to get
+/// `ArithmeticError::DivisionByZero` instead, or `checked_rem`.
+impl<const N: usize> Rem for BalTern<N> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.checked_rem(rhs).expect("Division by Zero")
+    }
+}
+
+impl<const N: usize> Neg for BalTern<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let mut value = self.value;
+        arith::negate(&mut value);
+        BalTern {value}
+    }
+}
+
+impl<const N: usize> BitAnd for BalTern<N> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.tritwise_min(rhs)
+    }
+}
+
+impl<const N: usize> BitOr for BalTern<N> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.tritwise_max(rhs)
+    }
+}
+
+/// Tritwise NOT swaps `1` and `T`, which is exactly negation.
+impl<const N: usize> Not for BalTern<N> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        -self
+    }
+}
+
+/// Like binary shifts, trits shifted past the top are silently dropped.
+impl<const N: usize> Shl<u32> for BalTern<N> {
+    type Output = Self;
+
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.shl_trits(rhs)
+    }
+}
+
+impl<const N: usize> Shr<u32> for BalTern<N> {
+    type Output = Self;
+
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.shr_trits(rhs)
+    }
+}
+
+#[cfg(test)]
+mod baltern_tests {
+    use super::*;
+
+    #[test]
+    fn odd_width() {
+        let seven: BalTern<7> = "1T1".parse().unwrap();
+        assert_eq!(seven.to_i128(), 7);
+        assert_eq!(format!("{:?}", seven), "T7(1T1 = 7)");
+        assert_eq!(BalTern::<7>::try_from_i128(1093), Ok(BalTern {value: [Bal3::One; 7]}));
+        assert_eq!(BalTern::<7>::try_from_i128(1094), Err(TryFromIntError::PosOverflow {width: Width::Trits(7), value: BigBalTern::from(1094)}));
+        assert_eq!(BalTern::<7>::try_from_i128(-1094), Err(TryFromIntError::NegOverflow {width: Width::Trits(7), value: BigBalTern::from(-1094)}));
+    }
+
+    #[test]
+    fn formatter_flags() {
+        let six = BalTern::<5>::try_from_i128(6).unwrap();
+        let neg = BalTern::<5>::try_from_i128(-6).unwrap();
+        assert_eq!(format!("{}|{}", BalTern::<5>::ZERO, BalTern::<0>::ZERO), "0|0");
+        assert_eq!(format!("{:#}|{:#}", six, BalTern::<3>::ZERO), "001T0|000");
+        assert_eq!(format!("{:0}|{:07}|{:02}", six, neg, six), "001T0|0000T10|1T0");
+        assert_eq!(format!("{:+}|{:+}|{:+}", six, neg, BalTern::<5>::ZERO), "+1T0|T10|+0");
+        assert_eq!(format!("{:+06}|{:+0}", six, six), "+001T0|+001T0");
+        assert_eq!(format!("[{:6}][{:<6}][{:^6}][{:*>6}]", six, six, six, neg), "[   1T0][1T0   ][ 1T0  ][***T10]");
+        assert_eq!(format!("[{:>+#8}]", six), "[  +001T0]");
+        assert_eq!(format!("{:?}", BalTern::<5>::ZERO), "T5(0 = 0)");
+        let word: BalTern<9> = "T10T11T01".parse().unwrap();
+        assert_eq!(format!("{:.3}|{:#.3}|{:.5}", word, word, word), "T10_T11_T01|T10_T11_T01|T10T_11T01");
+        assert_eq!(format!("{:.3}|{:#.3}|{:.1}", six, six, six), "1T0|00_1T0|1_T_0");
+        assert_eq!(format!("{:0.3}|{:08.3}|{:07.3}|{:+08.3}", six, six, six, six), "00_1T0|0000_1T0|000_1T0|+000_1T0");
+        assert_eq!(format!("{:012.3}", BalTern::<10>::try_from(-48).unwrap()), "0000_0T1_1T0");
+        assert_eq!(format!("[{:>9.2}]", six), "[     1_T0]");
+        assert_eq!(format!("{:?}", BalTern::<82>::MAX_VALUE), format!("T82({})", "1".repeat(82)));
+    }
+
+    #[test]
+    fn i128_extremes() {
+        assert!(BalTern::<81>::try_from_i128(i128::MAX).is_ok());
+        assert!(BalTern::<81>::try_from_i128(i128::MIN).is_ok());
+        assert_eq!(BalTern::<80>::try_from_i128(i128::MAX), Err(TryFromIntError::PosOverflow {width: Width::Trits(80), value: BigBalTern::from(i128::MAX)}));
+        assert_eq!(BalTern::<27>::try_from_i128(i128::MIN), Err(TryFromIntError::NegOverflow {width: Width::Trits(27), value: BigBalTern::from(i128::MIN)}));
+        assert_eq!(BalTern::<81>::try_from_u128(u128::MAX), Err(TryFromIntError::PosOverflow {width: Width::Trits(81), value: BigBalTern::from(u128::MAX)}));
+        assert!(BalTern::<82>::try_from_u128(u128::MAX).is_ok());
+        assert_eq!(BalTern::<81>::try_from_u128(1 << 127), BalTern::<81>::try_from_i128(i128::MIN).map(|x| -x));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a: BalTern<3> = "1T".parse().unwrap();
+        let b: BalTern<3> = "11".parse().unwrap();
+        assert_eq!((a + b).to_i128(), 6);
+        assert_eq!((a - b).to_i128(), -2);
+        assert_eq!((a * b).to_i128(), 8);
+        assert_eq!((-a).to_i128(), -2);
+    }
+
+    #[test]
+    fn matches_binary_arithmetic() {
+        for a in -121..=121 {
+            for b in -121..=121 {
+                let (x, y) = (BalTern::<5>::try_from_i128(a).unwrap(), BalTern::<5>::try_from_i128(b).unwrap());
+                if (a + b).abs() <= 121 {
+                    assert_eq!((x + y).to_i128(), a + b);
+                }
+                if (a - b).abs() <= 121 {
+                    assert_eq!((x - y).to_i128(), a - b);
+                }
+                if (a * b).abs() <= 121 {
+                    assert_eq!((x * y).to_i128(), a * b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn overflow_modes() {
+        let max = BalTern::<3>::try_from_i128(13).unwrap();
+        let min = BalTern::<3>::try_from_i128(-13).unwrap();
+        let one = BalTern::<3>::try_from_i128(1).unwrap();
+        let four = BalTern::<3>::try_from_i128(4).unwrap();
+
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(min.checked_sub(one), None);
+        assert_eq!(four.checked_mul(four), None);
+        assert_eq!(four.checked_mul(-one), Some(-four));
+        assert_eq!(min.checked_neg(), Some(max));
+
+        // 14 = 1TTT, 16 = 1TT1 and -14 = T111 with the top trit dropped
+        assert_eq!(max.wrapping_add(one).to_i128(), -13);
+        assert_eq!(four.wrapping_mul(four).to_i128(), -11);
+        assert_eq!(min.wrapping_sub(one).to_i128(), 13);
+
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(min.saturating_sub(one), min);
+        assert_eq!(four.saturating_mul(-four), min);
+        assert_eq!(four.saturating_add(one).to_i128(), 5);
+
+        assert_eq!(max.overflowing_add(one), (BalTern::try_from_i128(-13).unwrap(), Bal3::One));
+        assert_eq!(min.overflowing_sub(one), (max, Bal3::NegativeOne));
+        assert_eq!(max.overflowing_sub(-one), (min, Bal3::One));
+        assert_eq!(four.overflowing_mul(-four), (BalTern::try_from_i128(11).unwrap(), Bal3::NegativeOne));
+        assert_eq!(one.overflowing_add(one), (BalTern::try_from_i128(2).unwrap(), Bal3::Zero));
+    }
+
+    #[test]
+    fn multi_word() {
+        // 6 trit numbers as (high, low) pairs of 3 trit words
+        let words = |x: i128| -> [BalTern<3>; 2] { BalTern::<6>::try_from_i128(x).unwrap().split() };
+        let join = |high: BalTern<3>, low: BalTern<3>| BalTern::<6>::join([high, low]).to_i128();
+        for (a, b) in [(200, 164), (-364, 1), (13, 14), (-150, 99), (0, -364), (364, 364)] {
+            let ([a_high, a_low], [b_high, b_low]) = (words(a), words(b));
+            let (low, carry) = a_low.add_with_carry(b_low, Bal3::Zero);
+            let (high, carry) = a_high.add_with_carry(b_high, carry);
+            assert_eq!(join(high, low) + i128::from(i8::from(carry)) * 729, a + b);
+            let (low, borrow) = a_low.sub_with_borrow(b_low, Bal3::Zero);
+            let (high, borrow) = a_high.sub_with_borrow(b_high, borrow);
+            assert_eq!(join(high, low) - i128::from(i8::from(borrow)) * 729, a - b);
+        }
+        let t = |x: i128| BalTern::<3>::try_from_i128(x).unwrap();
+        assert_eq!(t(13).add_with_carry(t(13), Bal3::One), (t(0), Bal3::One));
+        assert_eq!(t(-13).sub_with_borrow(t(13), Bal3::One), (t(0), Bal3::One));
+    }
+
+    #[test]
+    fn full_multiplication() {
+        let t = |x: i128| BalTern::<3>::try_from_i128(x).unwrap();
+        for a in -13..=13 {
+            for b in -13..=13 {
+                let (high, low) = t(a).full_mul(t(b));
+                assert_eq!(high.to_i128() * 27 + low.to_i128(), a * b);
+                assert_eq!(low, t(a).wrapping_mul(t(b)));
+            }
+        }
+        // no primitive holds T80 * T80, but T40 * T40 fits in an i128
+        let max = BalTern::<40>::MAX_VALUE;
+        let (high, low) = max.full_mul(-max);
+        assert_eq!(high.to_i128() * 3_i128.pow(40) + low.to_i128(), -max.to_i128() * max.to_i128());
+    }
+
+    #[test]
+    fn division_modes() {
+        let t = |x: i128| BalTern::<5>::try_from_i128(x).unwrap();
+        for a in -121..=121 {
+            for b in (-121..=121).filter(|b| *b != 0) {
+                let (quot, rem) = t(a).div_rem(t(b)).unwrap();
+                assert_eq!((quot.to_i128(), rem.to_i128()), (a / b, a % b));
+                let (quot, rem) = t(a).div_rem_euclid(t(b)).unwrap();
+                assert_eq!((quot.to_i128(), rem.to_i128()), (a.div_euclid(b), a.rem_euclid(b)));
+                let (quot, rem) = t(a).div_rem_floor(t(b)).unwrap();
+                assert_eq!(quot.to_i128() * b + rem.to_i128(), a);
+                assert!(rem.to_i128() == 0 || rem.to_i128().signum() == b.signum());
+                let (quot, rem) = t(a).div_rem_balanced(t(b)).unwrap();
+                assert_eq!(quot.to_i128() * b + rem.to_i128(), a);
+                assert!(2 * rem.to_i128().abs() <= b.abs());
+            }
+        }
+    }
+
+    #[test]
+    fn division_by_zero() {
+        let zero = BalTern::<5>::try_from_i128(0).unwrap();
+        let six = BalTern::<5>::try_from_i128(6).unwrap();
+        assert_eq!(six.div_rem(zero), Err(ArithmeticError::DivisionByZero));
+        assert_eq!(six.div_euclid(zero), Err(ArithmeticError::DivisionByZero));
+        assert_eq!(six.checked_div(zero), None);
+    }
+
+    #[test]
+    fn wide_division() {
+        let big = BalTern::<80>::try_from_i128(-(3_i128.pow(79)) + 5).unwrap();
+        let small = BalTern::<80>::try_from_i128(3_i128.pow(40)).unwrap();
+        let (quot, rem) = big.div_rem(small).unwrap();
+        assert_eq!((quot.to_i128(), rem.to_i128()), (-(3_i128.pow(39)) + 1, 5 - 3_i128.pow(40)));
+        assert_eq!(big.div_balanced(small).unwrap().to_i128(), -(3_i128.pow(39)));
+    }
+
+    #[test]
+    fn tritwise_logic() {
+        let a: BalTern<9> = "TTT000111".parse().unwrap();
+        let b: BalTern<9> = "T01T01T01".parse().unwrap();
+        assert_eq!((a & b).to_string(), "TTTT00T01");
+        assert_eq!((a | b).to_string(), "T01001111");
+        assert_eq!((!a).to_string(), "111000TTT");
+        assert_eq!(a.tritwise_mul(b).to_string(), "10T000T01");
+        assert_eq!(a.consensus(b).to_string(), "T00000001");
+        assert_eq!(a.any(b).to_string(), "TT0T01011");
+    }
+
+    #[test]
+    fn shifts() {
+        let a: BalTern<5> = "1T01".parse().unwrap();
+        assert_eq!((a << 1).to_string(), "1T010");
+        assert_eq!(a.overflowing_shl_trits(1), ("1T010".parse().unwrap(), false));
+        assert_eq!(a.overflowing_shl_trits(2), ("T0100".parse().unwrap(), true));
+        assert_eq!(a.overflowing_shl_trits(9), ("0".parse().unwrap(), true));
+        assert_eq!((a >> 2).to_string(), "1T");
+        assert_eq!(a.overflowing_shr_trits(1), ("1T0".parse().unwrap(), true));
+        assert_eq!(a.rotate_left_trits(2), "T0101".parse().unwrap());
+        assert_eq!(a.rotate_right_trits(2), "0101T".parse().unwrap());
+        assert_eq!(a.rotate_right_trits(7), a.rotate_right_trits(2));
+    }
+
+    #[test]
+    fn shr_rounds_to_nearest() {
+        for x in -121..=121 {
+            let shifted = BalTern::<5>::try_from_i128(x).unwrap() >> 2;
+            assert!((shifted.to_i128() * 9 - x).abs() <= 4);
+        }
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(BalTern::<5>::ZERO.to_i128(), 0);
+        assert_eq!(BalTern::<5>::ONE.to_i128(), 1);
+        assert_eq!(BalTern::<5>::NEG_ONE.to_i128(), -1);
+        assert_eq!(BalTern::<5>::MAX_VALUE.to_i128(), 121);
+        assert_eq!(BalTern::<5>::MIN_VALUE.to_i128(), -121);
+        assert_eq!(BalTern::<7>::default(), BalTern::<7>::ZERO);
+    }
+
+    #[test]
+    fn numeric_order() {
+        let mut values: Vec<BalTern<5>> = (-121..=121).rev().map(|x| BalTern::try_from_i128(x).unwrap()).collect();
+        values.sort();
+        assert_eq!(values, (-121..=121).map(|x| BalTern::try_from_i128(x).unwrap()).collect::<Vec<_>>());
+        assert!(BalTern::<5>::NEG_ONE < BalTern::ZERO);
+        assert!(BalTern::<5>::try_from_i128(5).unwrap() > BalTern::try_from_i128(4).unwrap());
+        assert_eq!(BalTern::<5>::MIN_VALUE.max(BalTern::ONE), BalTern::ONE);
+    }
+
+    #[test]
+    fn hash_keys() {
+        use std::collections::HashMap;
+        let mut counts: HashMap<BalTern<5>, u8> = HashMap::new();
+        for x in [1, 2, 1, -1, 1] {
+            *counts.entry(BalTern::try_from_i128(x).unwrap()).or_default() += 1;
+        }
+        assert_eq!(counts[&BalTern::ONE], 3);
+        assert_eq!(counts[&BalTern::NEG_ONE], 1);
+    }
+
+    #[test]
+    fn resize() {
+        let six = BalTern::<3>::try_from_i128(6).unwrap();
+        assert_eq!(six.try_resize::<7>().unwrap().to_i128(), 6);
+        assert_eq!(six.try_resize::<2>(), Err(TryFromIntError::PosOverflow {width: Width::Trits(2), value: BigBalTern::from(6)}));
+        assert_eq!((-six).try_resize::<2>(), Err(TryFromIntError::NegOverflow {width: Width::Trits(2), value: BigBalTern::from(-6)}));
+        assert_eq!(BalTern::<7>::NEG_ONE.try_resize::<1>(), Ok(BalTern::NEG_ONE));
+    }
+
+    #[test]
+    fn wide_multiplication() {
+        // no primitive holds the 160 trit intermediate product
+        let big = BalTern::<80>::try_from_i128(3_i128.pow(40)).unwrap();
+        let small = BalTern::<80>::try_from_i128(-(3_i128.pow(39))).unwrap();
+        assert_eq!((big * small).to_i128(), -(3_i128.pow(79)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn mul_overflow_with_cancelling_partials() {
+        let _ = BalTern::<5>::try_from_i128(61).unwrap() * BalTern::<5>::try_from_i128(2).unwrap();
+    }
+
+    #[test]
+    fn mul_with_cancelling_partials() {
+        // 60 * 1T: the partial product 60 * 3 overflows but the total does not
+        let product = BalTern::<5>::try_from_i128(60).unwrap() * BalTern::<5>::try_from_i128(2).unwrap();
+        assert_eq!(product.to_i128(), 120);
+    }
+}
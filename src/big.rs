@@ -0,0 +1,305 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Sub, Neg, Mul, Div, Rem};
+use std::str::FromStr;
+use super::{Bal3, BalTern, TritAlphabet, DisplayWith, TryFromIntError, Width, ParseBalTernError, ArithmeticError, arith, literal};
+
+/// A balanced ternary integer that grows as needed instead of overflowing.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigBalTern {
+    // most significant trit first, never with leading zeros, so zero is empty
+    pub(crate) value: Vec<Bal3>
+}
+
+impl BigBalTern {
+    pub(crate) fn from_trits(mut value: Vec<Bal3>) -> Self {
+        let start = value.iter().position(|x| *x != Bal3::Zero).unwrap_or(value.len());
+        value.drain(..start);
+        BigBalTern {value}
+    }
+
+    /// The number of trits needed to write the value, zero for zero.
+    pub fn trit_len(&self) -> usize {
+        self.value.len()
+    }
+
+    fn signum(&self) -> Bal3 {
+        arith::signum(&self.value)
+    }
+
+    /// Quotient rounded towards zero and the remainder, which takes the sign
+    /// of `self`. This is what `/` and `%` do.
+    pub fn div_rem(self, rhs: Self) -> Result<(Self, Self), ArithmeticError> {
+        let (self_sign, rhs_sign) = (self.signum(), rhs.signum());
+        if rhs_sign == Bal3::Zero {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        let (mut rem, mut divisor) = (self.value, rhs.value);
+        if self_sign == Bal3::NegativeOne {
+            arith::negate(&mut rem);
+        }
+        if rhs_sign == Bal3::NegativeOne {
+            arith::negate(&mut divisor);
+        }
+        let mut quot = vec![Bal3::Zero; rem.len()];
+        arith::div_rem_magnitude(&mut rem, &divisor, &mut quot);
+        if self_sign != rhs_sign {
+            arith::negate(&mut quot);
+        }
+        if self_sign == Bal3::NegativeOne {
+            arith::negate(&mut rem);
+        }
+        Ok((BigBalTern::from_trits(quot), BigBalTern::from_trits(rem)))
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.div_rem(rhs).ok().map(|(quot, _)| quot)
+    }
+
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.div_rem(rhs).ok().map(|(_, rem)| rem)
+    }
+}
+
+impl BigBalTern {
+    /// Parses digits of `alphabet`, most significant first.
+    pub fn from_str_with(s: &str, alphabet: &TritAlphabet) -> Result<Self, ParseBalTernError> {
+        alphabet.parse(s).map(BigBalTern::from_trits)
+    }
+
+    /// Formats with `alphabet` in place of `T`, `0` and `1`.
+    pub fn display_with<'a>(&'a self, alphabet: &'a TritAlphabet<'a>) -> DisplayWith<'a, Self> {
+        DisplayWith {value: self, alphabet}
+    }
+}
+
+/// The trits and the value in decimal, `BigBalTern(1T0 = 6)`. The decimal is
+/// left out if it doesn't fit in an `i128`.
+impl fmt::Debug for BigBalTern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BigBalTern({}", self)?;
+        if let Ok(int) = i128::try_from(self.clone()) {
+            write!(f, " = {}", int)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Zero prints as `0`, and the formatter flags work as they do for
+/// `BalTern`.
+impl fmt::Display for BigBalTern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        TritAlphabet::STANDARD.write(f, &self.value)
+    }
+}
+
+impl fmt::Display for DisplayWith<'_, BigBalTern> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.alphabet.write(f, &self.value.value)
+    }
+}
+
+impl FromStr for BigBalTern {
+    type Err = ParseBalTernError;
+
+    /// Reads the same literals as `BalTern`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        literal::parse(s).map(BigBalTern::from_trits)
+    }
+}
+
+impl<const N: usize> From<BalTern<N>> for BigBalTern {
+    fn from(input: BalTern<N>) -> Self {
+        BigBalTern::from_trits(input.value.to_vec())
+    }
+}
+
+impl<const N: usize> TryFrom<BigBalTern> for BalTern<N> {
+    type Error = TryFromIntError;
+
+    fn try_from(input: BigBalTern) -> Result<Self, Self::Error> {
+        if input.value.len() > N {
+            return Err(TryFromIntError::overflow(Width::Trits(N), input));
+        }
+        let mut value = [Bal3::Zero; N];
+        value[N - input.value.len()..].copy_from_slice(&input.value);
+        Ok(BalTern {value})
+    }
+}
+
+// 81 trits hold any i128 and 82 any u128, so go through a fixed width
+macro_rules! impl_from_int {
+    ($width:literal: $($int:ty),*) => {
+        $(
+            impl From<$int> for BigBalTern {
+                fn from(int: $int) -> Self {
+                    BalTern::<$width>::try_from(int).expect("should always fit").into()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(81: i8, i16, i32, i64, i128, isize);
+impl_from_int!(82: u8, u16, u32, u64, u128, usize);
+
+impl TryFrom<BigBalTern> for i128 {
+    type Error = TryFromIntError;
+
+    fn try_from(input: BigBalTern) -> Result<Self, Self::Error> {
+        let overflow = || TryFromIntError::overflow(Width::Bits(128), input.clone());
+        // least significant first, so only the final sum can overflow
+        let mut acc: i128 = 0;
+        for (pow, trit) in input.value.iter().rev().enumerate() {
+            let place = u32::try_from(pow).ok().and_then(|pow| 3_i128.checked_pow(pow));
+            acc = match (trit, place) {
+                (Bal3::Zero, _) => acc,
+                (Bal3::One, Some(place)) => acc.checked_add(place).ok_or_else(overflow)?,
+                (Bal3::NegativeOne, Some(place)) => acc.checked_sub(place).ok_or_else(overflow)?,
+                (_, None) => return Err(overflow()),
+            }
+        }
+        Ok(acc)
+    }
+}
+
+impl Ord for BigBalTern {
+    fn cmp(&self, other: &Self) -> Ordering {
+        arith::cmp_shifted(&self.value, &other.value, 0)
+    }
+}
+
+impl PartialOrd for BigBalTern {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for BigBalTern {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let (long, short) = match self.value.len() >= rhs.value.len() {
+            true => (self.value, rhs.value),
+            false => (rhs.value, self.value),
+        };
+        // one spare trit on top always holds the carry
+        let mut value = vec![Bal3::Zero; long.len() + 1];
+        value[1..].copy_from_slice(&long);
+        arith::add_into(&mut value, &short, Bal3::Zero);
+        BigBalTern::from_trits(value)
+    }
+}
+
+impl Sub for BigBalTern {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for BigBalTern {
+    type Output = Self;
+
+    // the product needs as many trits as both operands together
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut value = vec![Bal3::Zero; self.value.len() + rhs.value.len()];
+        arith::mul_into(&mut value, &self.value, &rhs.value);
+        BigBalTern::from_trits(value)
+    }
+}
+
+/// Panics if `rhs` is zero. Use `div_rem` to get
+/// `ArithmeticError::DivisionByZero` instead, or `checked_div`.
+impl Div for BigBalTern {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(rhs).expect("Division by Zero")
+    }
+}
+
+/// Panics if `rhs` is zero. Use `div_rem` to get
+/// `ArithmeticError::DivisionByZero` instead, or `checked_rem`.
+impl Rem for BigBalTern {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.checked_rem(rhs).expect("Division by Zero")
+    }
+}
+
+impl Neg for BigBalTern {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let mut value = self.value;
+        arith::negate(&mut value);
+        BigBalTern {value}
+    }
+}
+
+#[cfg(test)]
+mod big_tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_display() {
+        let neg_forty_eight: BigBalTern = "T11T0".parse().unwrap();
+        assert_eq!(neg_forty_eight, BigBalTern::from(-48));
+        assert_eq!(format!("{}", neg_forty_eight), "T11T0");
+        assert_eq!(format!("{}", "0001T".parse::<BigBalTern>().unwrap()), "1T");
+        assert_eq!(format!("{}", BigBalTern::default()), "0");
+        assert_eq!(format!("{:?}", neg_forty_eight), "BigBalTern(T11T0 = -48)");
+        assert_eq!(format!("{:?}", BigBalTern::from(u128::MAX) * BigBalTern::from(3)), format!("BigBalTern({}0)", BigBalTern::from(u128::MAX)));
+        assert_eq!("".parse::<BigBalTern>(), Err(ParseBalTernError::Empty));
+        assert_eq!("11T#1".parse::<BigBalTern>(), Err(ParseBalTernError::InvalidChar {ch: '#', index: 3}));
+    }
+
+    #[test]
+    fn matches_binary_arithmetic() {
+        for a in -50_i128..=50 {
+            for b in -50_i128..=50 {
+                let (x, y) = (BigBalTern::from(a), BigBalTern::from(b));
+                assert_eq!(x.clone() + y.clone(), BigBalTern::from(a + b));
+                assert_eq!(x.clone() - y.clone(), BigBalTern::from(a - b));
+                assert_eq!(x.clone() * y.clone(), BigBalTern::from(a * b));
+                assert_eq!(x.cmp(&y), a.cmp(&b));
+                if b != 0 {
+                    assert_eq!(x.div_rem(y), Ok((BigBalTern::from(a / b), BigBalTern::from(a % b))));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn past_fixed_widths() {
+        let max = BigBalTern::from(i128::MAX);
+        let squared = max.clone() * max.clone();
+        assert_eq!(squared.clone() / max.clone(), max);
+        assert_eq!(squared.clone() % max.clone(), BigBalTern::default());
+        assert_eq!(i128::try_from(squared.clone()), Err(TryFromIntError::PosOverflow {width: Width::Bits(128), value: squared.clone()}));
+        assert_eq!(i128::try_from(-squared.clone()), Err(TryFromIntError::NegOverflow {width: Width::Bits(128), value: -squared}));
+        assert_eq!(i128::try_from(max), Ok(i128::MAX));
+        assert_eq!(i128::try_from(BigBalTern::from(i128::MIN)), Ok(i128::MIN));
+        assert_eq!(BigBalTern::from(u128::MAX).trit_len(), 82);
+    }
+
+    #[test]
+    fn fixed_width_conversions() {
+        let six = BalTern::<5>::try_from(6).unwrap();
+        assert_eq!(BigBalTern::from(six), BigBalTern::from(6));
+        assert_eq!(BalTern::<5>::try_from(BigBalTern::from(6)), Ok(six));
+        assert_eq!(BalTern::<5>::try_from(BigBalTern::from(122)), Err(TryFromIntError::PosOverflow {width: Width::Trits(5), value: BigBalTern::from(122)}));
+        assert_eq!(BalTern::<5>::try_from(BigBalTern::from(-122)), Err(TryFromIntError::NegOverflow {width: Width::Trits(5), value: BigBalTern::from(-122)}));
+        assert_eq!(BalTern::<5>::try_from(BigBalTern::default()), Ok(BalTern::ZERO));
+    }
+
+    #[test]
+    fn division_by_zero() {
+        assert_eq!(BigBalTern::from(6).div_rem(BigBalTern::default()), Err(ArithmeticError::DivisionByZero));
+        assert_eq!(BigBalTern::from(6).checked_rem(BigBalTern::default()), None);
+    }
+}
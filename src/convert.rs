@@ -0,0 +1,136 @@
+//! Conversions between the named widths and to and from the primitive
+//! integers. Widening always pads with zeros so it is a `From`; narrowing is
+//! a `TryFrom` that fails on lost trits.
+
+// Every width can try to hold every primitive, so these are generic.
+macro_rules! impl_try_from_int {
+    ($via:ident: $($int:ty),*) => {
+        $(
+            impl<const N: usize> TryFrom<$int> for $crate::BalTern<N> {
+                type Error = $crate::TryFromIntError;
+
+                fn try_from(int: $int) -> Result<Self, Self::Error> {
+                    Self::$via(int as _)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_int!(try_from_i128: i8, i16, i32, i64, i128, isize);
+impl_try_from_int!(try_from_u128: u8, u16, u32, u64, u128, usize);
+
+// Which primitives a width always fits in depends on the width, so each
+// width module lists its own. Unused if every width feature is disabled.
+#[allow(unused_macros)]
+macro_rules! impl_into_int {
+    ($width:ty; From: $($from:ty),*; TryFrom: $($try:ty),*) => {
+        $(
+            impl From<$width> for $from {
+                fn from(input: $width) -> Self {
+                    input.to_i128() as $from
+                }
+            }
+        )*
+        $(
+            impl TryFrom<$width> for $try {
+                type Error = $crate::TryFromIntError;
+
+                fn try_from(input: $width) -> Result<Self, Self::Error> {
+                    <$try>::try_from(input.to_i128())
+                        .map_err(|_| $crate::TryFromIntError::overflow($crate::Width::Bits(<$try>::BITS as usize), input.into()))
+                }
+            }
+        )*
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use impl_into_int;
+
+// Takes the widths in ascending order and implements every pair, each one
+// only when both widths' features are enabled.
+macro_rules! impl_resize {
+    () => {};
+    (($narrow:literal, $narrow_feature:literal) $(, ($wide:literal, $wide_feature:literal))*) => {
+        $(
+            #[cfg(all(feature = $narrow_feature, feature = $wide_feature))]
+            impl From<$crate::BalTern<$narrow>> for $crate::BalTern<$wide> {
+                fn from(input: $crate::BalTern<$narrow>) -> Self {
+                    input.try_resize().expect("widening should never overflow")
+                }
+            }
+
+            #[cfg(all(feature = $narrow_feature, feature = $wide_feature))]
+            impl TryFrom<$crate::BalTern<$wide>> for $crate::BalTern<$narrow> {
+                type Error = $crate::TryFromIntError;
+
+                fn try_from(input: $crate::BalTern<$wide>) -> Result<Self, Self::Error> {
+                    input.try_resize()
+                }
+            }
+        )*
+        impl_resize!($(($wide, $wide_feature)),*);
+    };
+}
+
+impl_resize!(
+    (3, "t3"), (5, "t5"), (6, "t6"), (9, "t9"), (10, "t10"),
+    (18, "t18"), (20, "t20"), (27, "t27"), (40, "t40"), (80, "t80")
+);
+
+// the product of two N trit numbers always fits in 2N trits, so multiply in
+// the narrowest width at least that wide
+macro_rules! impl_widening_mul {
+    ($(($narrow:literal, $narrow_feature:literal) => ($wide:literal, $wide_feature:literal)),*) => {
+        $(
+            #[cfg(all(feature = $narrow_feature, feature = $wide_feature))]
+            impl $crate::BalTern<$narrow> {
+                #[doc = concat!("The exact product as a ", stringify!($wide), " trit number, which can never overflow.")]
+                pub fn widening_mul(self, rhs: Self) -> $crate::BalTern<$wide> {
+                    $crate::BalTern::<$wide>::from(self).wrapping_mul(rhs.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_widening_mul!(
+    (3, "t3") => (6, "t6"), (5, "t5") => (10, "t10"), (6, "t6") => (18, "t18"),
+    (9, "t9") => (18, "t18"), (10, "t10") => (20, "t20"), (18, "t18") => (40, "t40"),
+    (20, "t20") => (40, "t40"), (27, "t27") => (80, "t80"), (40, "t40") => (80, "t80")
+);
+
+#[cfg(all(test, feature = "t5", feature = "t10", feature = "t20", feature = "t27", feature = "t40", feature = "t80"))]
+mod convert_tests {
+    use crate::{T5, T10, T20, T27, T40, T80, BigBalTern, TryFromIntError, Width};
+
+    #[test]
+    fn widening() {
+        let neg_forty_eight = T5::try_from(-48).unwrap();
+        assert_eq!(T10::from(neg_forty_eight), T10::try_from(-48).unwrap());
+        assert_eq!(T20::from(neg_forty_eight), T20::try_from(-48).unwrap());
+        assert_eq!(T40::from(T20::try_from(T20::MAX).unwrap()), T40::try_from(T20::MAX as i64).unwrap());
+        assert_eq!(i128::from(T80::from(T40::MIN_VALUE)), T40::MIN as i128);
+        assert_eq!(T27::from(T20::MAX_VALUE), T27::try_from(T20::MAX).unwrap());
+    }
+
+    #[test]
+    fn narrowing() {
+        assert_eq!(T5::try_from(T40::try_from(-48).unwrap()), Ok(T5::try_from(-48).unwrap()));
+        assert_eq!(T10::try_from(T20::try_from(T10::MAX as i32 + 1).unwrap()), Err(TryFromIntError::PosOverflow {width: Width::Trits(10), value: BigBalTern::from(T10::MAX as i32 + 1)}));
+        assert_eq!(T20::try_from(T40::try_from(T20::MIN as i64 - 1).unwrap()), Err(TryFromIntError::NegOverflow {width: Width::Trits(20), value: BigBalTern::from(T20::MIN as i64 - 1)}));
+        assert_eq!(T5::try_from(T10::MAX_VALUE), Err(TryFromIntError::PosOverflow {width: Width::Trits(5), value: T10::MAX_VALUE.into()}));
+        assert_eq!(T27::try_from(T80::try_from(T27::MIN as i128 - 1).unwrap()), Err(TryFromIntError::NegOverflow {width: Width::Trits(27), value: BigBalTern::from(T27::MIN as i128 - 1)}));
+        assert_eq!(T40::try_from(T80::from(T27::MAX_VALUE)), Ok(T40::from(T27::MAX_VALUE)));
+    }
+
+    #[test]
+    fn widening_multiplication() {
+        assert_eq!(T5::MAX_VALUE.widening_mul(T5::MIN_VALUE), T10::try_from(-121 * 121).unwrap());
+        assert_eq!(i32::from(T10::MAX_VALUE.widening_mul(T10::MAX_VALUE)), T10::MAX as i32 * T10::MAX as i32);
+        assert_eq!(i64::from(T20::MIN_VALUE.widening_mul(T20::MAX_VALUE)), T20::MIN as i64 * T20::MAX as i64);
+        assert_eq!(i128::from(T27::MAX_VALUE.widening_mul(T27::MAX_VALUE)), T27::MAX as i128 * T27::MAX as i128);
+        assert_eq!(i128::from(T40::MIN_VALUE.widening_mul(T40::MIN_VALUE)), T40::MIN as i128 * T40::MIN as i128);
+    }
+}
@@ -0,0 +1,230 @@
+//! The crate's errors. Each operation returns its own error type, and
+//! `Error` gathers them all for callers that mix operations with `?`.
+
+use std::error;
+use std::fmt;
+use super::BigBalTern;
+
+/// The size of a conversion's target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Width {
+    /// A balanced ternary target.
+    Trits(usize),
+    /// A primitive integer target.
+    Bits(usize),
+}
+
+impl fmt::Display for Width {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Width::Trits(1) => write!(f, "1 trit"),
+            Width::Trits(trits) => write!(f, "{} trits", trits),
+            Width::Bits(bits) => write!(f, "{} bit integers", bits),
+        }
+    }
+}
+
+/// A value didn't fit the target of a conversion. `value` is the value that
+/// didn't fit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryFromIntError {
+    PosOverflow { width: Width, value: BigBalTern },
+    NegOverflow { width: Width, value: BigBalTern },
+}
+
+impl TryFromIntError {
+    /// The overflow in the direction of `value`'s sign.
+    pub(crate) fn overflow(width: Width, value: BigBalTern) -> Self {
+        match value < BigBalTern::default() {
+            true => TryFromIntError::NegOverflow {width, value},
+            false => TryFromIntError::PosOverflow {width, value},
+        }
+    }
+}
+
+impl fmt::Display for TryFromIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryFromIntError::PosOverflow {width, value} => write!(f, "{} is above the maximum for {}", value, width),
+            TryFromIntError::NegOverflow {width, value} => write!(f, "{} is below the minimum for {}", value, width),
+        }
+    }
+}
+
+impl error::Error for TryFromIntError {}
+
+/// Only true and false trits convert to `bool`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryFromBal3Error {
+    Unknown,
+}
+
+impl fmt::Display for TryFromBal3Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryFromBal3Error::Unknown => write!(f, "an unknown trit is neither true nor false"),
+        }
+    }
+}
+
+impl error::Error for TryFromBal3Error {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArithmeticError {
+    DivisionByZero,
+}
+
+impl fmt::Display for ArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArithmeticError::DivisionByZero => write!(f, "division by zero"),
+        }
+    }
+}
+
+impl error::Error for ArithmeticError {}
+
+/// Why a string couldn't be parsed.
+///
+/// `index` is the byte offset of `ch` in the string. For overflows, `width`
+/// is the number of trits available and `value` is what was read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBalTernError {
+    PosOverflow { width: usize, value: BigBalTern },
+    NegOverflow { width: usize, value: BigBalTern },
+    InvalidChar { ch: char, index: usize },
+    Empty,
+}
+
+impl ParseBalTernError {
+    /// The overflow in the direction of `value`'s sign.
+    pub(crate) fn overflow(width: usize, value: BigBalTern) -> Self {
+        match value < BigBalTern::default() {
+            true => ParseBalTernError::NegOverflow {width, value},
+            false => ParseBalTernError::PosOverflow {width, value},
+        }
+    }
+
+    /// Moves the index of an invalid character along by `offset` bytes, for
+    /// errors from parsing part of a string.
+    pub(crate) fn offset(self, offset: usize) -> Self {
+        match self {
+            ParseBalTernError::InvalidChar {ch, index} => ParseBalTernError::InvalidChar {ch, index: index + offset},
+            error => error,
+        }
+    }
+}
+
+impl fmt::Display for ParseBalTernError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseBalTernError::PosOverflow {width, value} => write!(f, "{} is above the maximum for {} trits", value, width),
+            ParseBalTernError::NegOverflow {width, value} => write!(f, "{} is below the minimum for {} trits", value, width),
+            ParseBalTernError::InvalidChar {ch, index} => write!(f, "invalid digit {:?} at byte {}", ch, index),
+            ParseBalTernError::Empty => write!(f, "cannot parse a number from an empty string"),
+        }
+    }
+}
+
+impl error::Error for ParseBalTernError {}
+
+/// Any of the crate's errors. `Display` only says which kind of error it
+/// is, and the wrapped error, with the details, is the `source`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    TryFromInt(TryFromIntError),
+    TryFromBal3(TryFromBal3Error),
+    Arithmetic(ArithmeticError),
+    Parse(ParseBalTernError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::TryFromInt(_) => write!(f, "integer conversion error"),
+            Error::TryFromBal3(_) => write!(f, "trit conversion error"),
+            Error::Arithmetic(_) => write!(f, "arithmetic error"),
+            Error::Parse(_) => write!(f, "parse error"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::TryFromInt(error) => Some(error),
+            Error::TryFromBal3(error) => Some(error),
+            Error::Arithmetic(error) => Some(error),
+            Error::Parse(error) => Some(error),
+        }
+    }
+}
+
+impl From<TryFromIntError> for Error {
+    fn from(error: TryFromIntError) -> Self {
+        Error::TryFromInt(error)
+    }
+}
+
+impl From<TryFromBal3Error> for Error {
+    fn from(error: TryFromBal3Error) -> Self {
+        Error::TryFromBal3(error)
+    }
+}
+
+impl From<ArithmeticError> for Error {
+    fn from(error: ArithmeticError) -> Self {
+        Error::Arithmetic(error)
+    }
+}
+
+impl From<ParseBalTernError> for Error {
+    fn from(error: ParseBalTernError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+#[cfg(test)]
+mod error_tests {
+    use super::*;
+    use std::error::Error as _;
+    use crate::BalTern;
+
+    #[test]
+    fn messages() {
+        let big = TryFromIntError::overflow(Width::Trits(5), BigBalTern::from(122));
+        assert_eq!(big.to_string(), "1TTTTT is above the maximum for 5 trits");
+        assert_eq!(TryFromIntError::overflow(Width::Bits(8), BigBalTern::from(-129)).to_string(), "T111T0 is below the minimum for 8 bit integers");
+        assert_eq!(crate::Bal3::try_from(2).unwrap_err().to_string(), "1T is above the maximum for 1 trit");
+        assert_eq!("1T0#".parse::<BalTern<5>>().unwrap_err().to_string(), "invalid digit '#' at byte 3");
+        assert_eq!("1T0T1T".parse::<BalTern<5>>().unwrap_err().to_string(), "1T0T1T is above the maximum for 5 trits");
+        assert_eq!(ParseBalTernError::Empty.to_string(), "cannot parse a number from an empty string");
+        assert_eq!(ArithmeticError::DivisionByZero.to_string(), "division by zero");
+        assert_eq!(TryFromBal3Error::Unknown.to_string(), "an unknown trit is neither true nor false");
+    }
+
+    #[test]
+    fn unified() {
+        fn parse_and_divide(a: &str, b: &str) -> Result<BalTern<5>, Error> {
+            let (a, b): (BalTern<5>, BalTern<5>) = (a.parse()?, b.parse()?);
+            Ok(a.div_rem(b)?.0)
+        }
+        assert_eq!(parse_and_divide("1T0", "1T"), Ok(BalTern::try_from(3).unwrap()));
+        let error = parse_and_divide("1T0", "0").unwrap_err();
+        assert_eq!(error, Error::Arithmetic(ArithmeticError::DivisionByZero));
+        assert_eq!(error.to_string(), "arithmetic error");
+        assert_eq!(error.source().map(|x| x.to_string()), Some("division by zero".to_string()));
+        let error = parse_and_divide("1T0", "1x").unwrap_err();
+        assert_eq!(error.to_string(), "parse error");
+        assert_eq!(error.source().map(|x| x.to_string()), Some("invalid digit 'x' at byte 1".to_string()));
+        assert_eq!(Error::from(TryFromBal3Error::Unknown), Error::TryFromBal3(TryFromBal3Error::Unknown));
+    }
+
+    #[test]
+    fn boxed() {
+        fn parse(s: &str) -> Result<BalTern<5>, Box<dyn std::error::Error>> {
+            Ok(s.parse()?)
+        }
+        assert_eq!(parse("").unwrap_err().to_string(), "cannot parse a number from an empty string");
+    }
+}
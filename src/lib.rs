@@ -1,53 +1,202 @@
+use std::cmp::Ordering;
 use std::fmt;
+use std::ops::{Neg, Mul};
 
+#[cfg(any(feature = "t3", feature = "t5", feature = "t6", feature = "t9", feature = "t10",
+    feature = "t18", feature = "t20", feature = "t27", feature = "t40", feature = "t80"))]
+mod any;
+mod alphabet;
+mod arith;
+mod baltern;
+mod big;
+mod convert;
+mod error;
+mod literal;
+pub mod logic;
+pub mod prelude;
+mod promote;
+mod trits;
+#[cfg(feature = "t3")]
+mod t3;
+#[cfg(feature = "t5")]
 mod t5;
+#[cfg(feature = "t6")]
+mod t6;
+#[cfg(feature = "t9")]
+mod t9;
+#[cfg(feature = "t10")]
 mod t10;
+#[cfg(feature = "t18")]
+mod t18;
+#[cfg(feature = "t20")]
 mod t20;
+#[cfg(feature = "t27")]
+mod t27;
+#[cfg(feature = "t40")]
 mod t40;
+#[cfg(feature = "t80")]
+mod t80;
+#[cfg(any(feature = "t3", feature = "t5", feature = "t6", feature = "t9", feature = "t10",
+    feature = "t18", feature = "t20", feature = "t27", feature = "t40", feature = "t80"))]
+pub use any::AnyBalTern;
+pub use alphabet::{TritAlphabet, DisplayWith};
+pub use baltern::BalTern;
+pub use big::BigBalTern;
+pub use error::{Error, Width, TryFromIntError, TryFromBal3Error, ArithmeticError, ParseBalTernError};
+#[cfg(feature = "t3")]
+pub use t3::T3;
+#[cfg(feature = "t5")]
+pub use t5::T5;
+#[cfg(feature = "t6")]
+pub use t6::T6;
+#[cfg(feature = "t9")]
+pub use t9::T9;
+#[cfg(feature = "t10")]
+pub use t10::T10;
+#[cfg(feature = "t18")]
+pub use t18::T18;
+#[cfg(feature = "t20")]
+pub use t20::T20;
+#[cfg(feature = "t27")]
+pub use t27::T27;
+#[cfg(feature = "t40")]
+pub use t40::T40;
+#[cfg(feature = "t80")]
+pub use t80::T80;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum Bal3 {
     One,
+    #[default]
     Zero,
     NegativeOne,
 }
 
 impl fmt::Display for Bal3 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(TritAlphabet::STANDARD.digit(*self))
+    }
+}
+
+impl Bal3 {
+    /// Adds two trits, returning `(sum, carry)`: `1 + 1 = 1T`.
+    pub fn half_add(self, rhs: Bal3) -> (Bal3, Bal3) {
+        self.full_add(rhs, Bal3::Zero)
+    }
+
+    /// Adds two trits and an incoming carry, returning `(sum, carry)`.
+    ///
+    /// The total is always in `-3..=3`, so both the sum and the carry are
+    /// single trits: `1 + 1 + 0 = 1T`, `1 + 1 + 1 = 10`.
+    pub fn full_add(self, rhs: Bal3, carry: Bal3) -> (Bal3, Bal3) {
+        match i8::from(self) + i8::from(rhs) + i8::from(carry) {
+            -3 => (Bal3::Zero, Bal3::NegativeOne),
+            -2 => (Bal3::One, Bal3::NegativeOne),
+            -1 => (Bal3::NegativeOne, Bal3::Zero),
+            0 => (Bal3::Zero, Bal3::Zero),
+            1 => (Bal3::One, Bal3::Zero),
+            2 => (Bal3::NegativeOne, Bal3::One),
+            3 => (Bal3::Zero, Bal3::One),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Reads a single digit of `alphabet`.
+    pub fn from_str_with(s: &str, alphabet: &TritAlphabet) -> Result<Bal3, ParseBalTernError> {
+        alphabet.trit(s)
+    }
+
+    pub fn display_with<'a>(&'a self, alphabet: &'a TritAlphabet<'a>) -> DisplayWith<'a, Self> {
+        DisplayWith {value: self, alphabet}
+    }
+
+    pub(crate) fn from_sign(int: i8) -> Bal3 {
+        match int.signum() {
+            1 => Bal3::One,
+            0 => Bal3::Zero,
+            _ => Bal3::NegativeOne,
+        }
+    }
+}
+
+/// Trits order by value, `T < 0 < 1`, not by declaration order.
+impl Ord for Bal3 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        i8::from(*self).cmp(&i8::from(*other))
+    }
+}
+
+impl PartialOrd for Bal3 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for Bal3 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
         match self {
-            Bal3::One => write!(f, "{}", String::from('1')),
-            Bal3::Zero => write!(f, "{}", String::from('0')),
-            Bal3::NegativeOne => write!(f, "{}", String::from('T')),
+            Bal3::One => Bal3::NegativeOne,
+            Bal3::Zero => Bal3::Zero,
+            Bal3::NegativeOne => Bal3::One,
         }
     }
 }
-#[derive(Debug, PartialEq)]
-pub enum TryFromIntError {
-    PosOverflow,
-    NegOverflow,
+
+/// The product of two trits is always a trit, so this can't overflow.
+impl Mul for Bal3 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Bal3::from_sign(i8::from(self) * i8::from(rhs))
+    }
 }
 
-#[derive(Debug, PartialEq)]
-pub enum ParseBalTernError {
-    PosOverflow,
-    NegOverflow,
-    InvalidChar,
-    Empty,
+impl From<Bal3> for i8 {
+    fn from(input: Bal3) -> Self {
+        match input {
+            Bal3::One => 1,
+            Bal3::Zero => 0,
+            Bal3::NegativeOne => -1,
+        }
+    }
+}
+
+impl TryFrom<i8> for Bal3 {
+    type Error = TryFromIntError;
+
+    fn try_from(int: i8) -> Result<Self, Self::Error> {
+        match int {
+            1 => Ok(Bal3::One),
+            0 => Ok(Bal3::Zero),
+            -1 => Ok(Bal3::NegativeOne),
+            _ => Err(TryFromIntError::overflow(Width::Trits(1), BigBalTern::from(int))),
+        }
+    }
 }
 
-fn find_first_non_zero(int: isize) -> (u8, bool) {
-    let pos = int >= 0;
-    let modulus: usize = int.unsigned_abs();
-    let mut max_num: usize = 0;
-    let mut index: u8 = 0;
-    for pow in 0..40_u8 {
-        max_num += 3_usize.pow(pow as u32);
-        if max_num >= modulus {
-            index = pow;
-            break;
+impl From<Bal3> for char {
+    fn from(input: Bal3) -> Self {
+        match input {
+            Bal3::One => '1',
+            Bal3::Zero => '0',
+            Bal3::NegativeOne => 'T',
+        }
+    }
+}
+
+impl TryFrom<char> for Bal3 {
+    type Error = ParseBalTernError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '1' => Ok(Bal3::One),
+            '0' => Ok(Bal3::Zero),
+            'T' => Ok(Bal3::NegativeOne),
+            _ => Err(ParseBalTernError::InvalidChar {ch: c, index: 0}),
         }
     }
-    (index, pos)
 }
 
 /* fn calc_max_int_from_length(bits: u8) -> u64 {
@@ -62,9 +211,9 @@ fn find_first_non_zero(int: isize) -> (u8, bool) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use t5::T5;
 
     #[test]
+    #[cfg(feature = "t5")]
     fn general() {
         //println!("Max num is {}", calc_max_int_from_length(40));
 
@@ -81,6 +230,7 @@ mod tests {
         assert_eq!(display_one, "1");
         assert_eq!(display_zero, "0");
         assert_eq!(display_negone, "T");
+        assert_eq!(format!("[{:>3}][{:*<3}]", Bal3::One, Bal3::NegativeOne), "[  1][T**]");
     }
 
     #[test]
@@ -93,12 +243,52 @@ mod tests {
         assert_eq!(debug_negone, "NegativeOne");        
     }
 
+    #[test]
+    fn bal3_full_add() {
+        use Bal3::{One, Zero, NegativeOne};
+        assert_eq!(One.full_add(One, One), (Zero, One));
+        assert_eq!(One.full_add(One, Zero), (NegativeOne, One));
+        assert_eq!(One.full_add(NegativeOne, Zero), (Zero, Zero));
+        assert_eq!(NegativeOne.full_add(NegativeOne, Zero), (One, NegativeOne));
+        assert_eq!(NegativeOne.full_add(NegativeOne, NegativeOne), (Zero, NegativeOne));
+        assert_eq!(Zero.full_add(Zero, NegativeOne), (NegativeOne, Zero));
+    }
+
+    #[test]
+    fn bal3_arithmetic() {
+        use Bal3::{One, Zero, NegativeOne};
+        assert_eq!((-One, -Zero, -NegativeOne), (NegativeOne, Zero, One));
+        assert_eq!((One * One, One * NegativeOne, NegativeOne * NegativeOne, Zero * One), (One, NegativeOne, One, Zero));
+        assert_eq!(One.half_add(One), (NegativeOne, One));
+        assert_eq!(NegativeOne.half_add(One), (Zero, Zero));
+        assert_eq!(NegativeOne.half_add(NegativeOne), (One, NegativeOne));
+    }
+
+    #[test]
+    fn bal3_conversions() {
+        for trit in [Bal3::One, Bal3::Zero, Bal3::NegativeOne] {
+            assert_eq!(Bal3::try_from(i8::from(trit)), Ok(trit));
+            assert_eq!(Bal3::try_from(char::from(trit)), Ok(trit));
+        }
+        assert_eq!(Bal3::try_from(2), Err(TryFromIntError::PosOverflow {width: Width::Trits(1), value: BigBalTern::from(2)}));
+        assert_eq!(Bal3::try_from(-2), Err(TryFromIntError::NegOverflow {width: Width::Trits(1), value: BigBalTern::from(-2)}));
+        assert_eq!(Bal3::try_from('t'), Err(ParseBalTernError::InvalidChar {ch: 't', index: 0}));
+    }
+
+    #[test]
+    fn bal3_order() {
+        let mut trits = [Bal3::One, Bal3::NegativeOne, Bal3::Zero];
+        trits.sort();
+        assert_eq!(trits, [Bal3::NegativeOne, Bal3::Zero, Bal3::One]);
+        assert_eq!(Bal3::default(), Bal3::Zero);
+    }
+
     #[test]
     fn try_from_int_error_display() {
-        let pos = TryFromIntError::PosOverflow;
-        let neg = TryFromIntError::NegOverflow;
-        assert_eq!(format!("{:?}", pos), "PosOverflow");
-        assert_eq!(format!("{:?}", neg), "NegOverflow");
+        let pos = TryFromIntError::overflow(Width::Trits(1), BigBalTern::from(2));
+        let neg = TryFromIntError::overflow(Width::Trits(1), BigBalTern::from(-2));
+        assert_eq!(format!("{:?}", pos), "PosOverflow { width: Trits(1), value: BigBalTern(1T = 2) }");
+        assert_eq!(format!("{:?}", neg), "NegOverflow { width: Trits(1), value: BigBalTern(T1 = -2) }");
     }
 
 }
@@ -0,0 +1,126 @@
+//! Radix-prefixed literals, which is what `FromStr` reads.
+//!
+//! Without a prefix, or with `0t`, the digits are balanced ternary. The other
+//! prefixes are `0d` for decimal, `0n` for balanced nonary and `0x` for
+//! hexadecimal. Decimal and hexadecimal take an optional `+` or `-` after the
+//! prefix. Any literal can be split up with `_`.
+//!
+//! Balanced nonary digits run from -4 to 4 and are written `W`, `X`, `Y`,
+//! `Z`, `0`, `1`, `2`, `3`, `4`, so `Z` is -1 and `W` is -4. Each nonary digit
+//! is exactly two trits.
+//!
+//! ```
+//! use balanced_base::BalTern;
+//!
+//! let neg_forty_eight = BalTern::<6>::try_from(-48).unwrap();
+//! assert_eq!("T11T0".parse(), Ok(neg_forty_eight));
+//! assert_eq!("0tT_11T0".parse(), Ok(neg_forty_eight));
+//! assert_eq!("0d-48".parse(), Ok(neg_forty_eight));
+//! assert_eq!("0nZ4X".parse(), Ok(neg_forty_eight));
+//! assert_eq!("0x-30".parse(), Ok(neg_forty_eight));
+//! ```
+
+use super::{Bal3, BigBalTern, TritAlphabet, ParseBalTernError};
+
+/// The trits of a literal, most significant first.
+pub(crate) fn parse(s: &str) -> Result<Vec<Bal3>, ParseBalTernError> {
+    // error indices are into the whole literal, prefix included
+    match s.get(..2) {
+        Some("0t") => TritAlphabet::STANDARD.parse(&s[2..]).map_err(|error| error.offset(2)),
+        Some("0d") => parse_radix(&s[2..], 10).map_err(|error| error.offset(2)),
+        Some("0n") => parse_nonary(&s[2..]).map_err(|error| error.offset(2)),
+        Some("0x") => parse_radix(&s[2..], 16).map_err(|error| error.offset(2)),
+        _ => TritAlphabet::STANDARD.parse(s),
+    }
+}
+
+/// Signed digits in an unbalanced radix, accumulated exactly so the width
+/// check is left to the caller.
+fn parse_radix(s: &str, radix: u32) -> Result<Vec<Bal3>, ParseBalTernError> {
+    let (negative, start) = match s.chars().next() {
+        Some('-') => (true, 1),
+        Some('+') => (false, 1),
+        _ => (false, 0),
+    };
+    let mut value = BigBalTern::default();
+    let mut empty = true;
+    for (index, ch) in s.char_indices().skip(start).filter(|(_, ch)| *ch != '_') {
+        let digit = ch.to_digit(radix).ok_or(ParseBalTernError::InvalidChar {ch, index})?;
+        value = value * BigBalTern::from(radix) + BigBalTern::from(digit);
+        empty = false;
+    }
+    if empty {
+        return Err(ParseBalTernError::Empty);
+    }
+    Ok(match negative {
+        true => -value,
+        false => value,
+    }.value)
+}
+
+fn parse_nonary(s: &str) -> Result<Vec<Bal3>, ParseBalTernError> {
+    use Bal3::{One, Zero, NegativeOne};
+    let mut trits = Vec::with_capacity(2 * s.len());
+    for (index, ch) in s.char_indices().filter(|(_, ch)| *ch != '_') {
+        trits.extend_from_slice(match ch {
+            '4' => &[One, One],
+            '3' => &[One, Zero],
+            '2' => &[One, NegativeOne],
+            '1' => &[Zero, One],
+            '0' => &[Zero, Zero],
+            'Z' => &[Zero, NegativeOne],
+            'Y' => &[NegativeOne, One],
+            'X' => &[NegativeOne, Zero],
+            'W' => &[NegativeOne, NegativeOne],
+            _ => return Err(ParseBalTernError::InvalidChar {ch, index}),
+        });
+    }
+    match trits.is_empty() {
+        true => Err(ParseBalTernError::Empty),
+        false => Ok(trits),
+    }
+}
+
+#[cfg(test)]
+mod literal_tests {
+    use super::*;
+    use crate::BalTern;
+
+    #[test]
+    fn prefixes() {
+        for int in -364_i128..=364 {
+            let expected = BalTern::<6>::try_from(int).unwrap();
+            assert_eq!(format!("0d{}", int).parse(), Ok(expected));
+            assert_eq!(format!("0t{}", expected).parse(), Ok(expected));
+            let hex = match int < 0 {
+                true => format!("0x-{:x}", -int),
+                false => format!("0x{:X}", int),
+            };
+            assert_eq!(hex.parse(), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn nonary() {
+        assert_eq!("0n4".parse(), Ok(BalTern::<2>::try_from(4).unwrap()));
+        assert_eq!("0nW".parse(), Ok(BalTern::<2>::try_from(-4).unwrap()));
+        assert_eq!("0n1Z".parse(), Ok(BalTern::<4>::try_from(8).unwrap()));
+        assert_eq!("0n4_444".parse(), Ok(BalTern::<8>::MAX_VALUE));
+        assert_eq!("0n44444".parse::<BalTern<9>>(), Err(ParseBalTernError::PosOverflow {width: 9, value: "1".repeat(10).parse().unwrap()}));
+        assert_eq!("0n12a".parse::<BalTern<9>>(), Err(ParseBalTernError::InvalidChar {ch: 'a', index: 4}));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!("0d".parse::<BalTern<5>>(), Err(ParseBalTernError::Empty));
+        assert_eq!("0x-_".parse::<BalTern<5>>(), Err(ParseBalTernError::Empty));
+        assert_eq!("0t".parse::<BalTern<5>>(), Err(ParseBalTernError::Empty));
+        assert_eq!("0d12a".parse::<BalTern<5>>(), Err(ParseBalTernError::InvalidChar {ch: 'a', index: 4}));
+        assert_eq!("0x-1g".parse::<BalTern<5>>(), Err(ParseBalTernError::InvalidChar {ch: 'g', index: 4}));
+        assert_eq!("0t1T2".parse::<BalTern<5>>(), Err(ParseBalTernError::InvalidChar {ch: '2', index: 4}));
+        assert_eq!("0d122".parse::<BalTern<5>>(), Err(ParseBalTernError::PosOverflow {width: 5, value: BigBalTern::from(122)}));
+        assert_eq!("0d-1_000".parse::<BalTern<5>>(), Err(ParseBalTernError::NegOverflow {width: 5, value: BigBalTern::from(-1000)}));
+        assert_eq!("0d+121".parse(), Ok(BalTern::<5>::MAX_VALUE));
+        assert_eq!("0x1_0000_0000_0000_0000_0000_0000_0000_0000".parse::<BigBalTern>(), Ok(BigBalTern::from(u128::MAX) + BigBalTern::from(1)));
+    }
+}
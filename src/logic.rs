@@ -0,0 +1,168 @@
+//! Three-valued logic on `Bal3`, reading `1` as true, `0` as unknown and `T`
+//! as false.
+//!
+//! Each logic is a unit struct implementing [`Logic`]:
+//!
+//! ```
+//! use balanced_base::Bal3;
+//! use balanced_base::logic::{Logic, Kleene, Bochvar};
+//!
+//! let unknown = Bal3::from(None);
+//! assert_eq!(Kleene::or(unknown, Bal3::from(true)), Bal3::One);
+//! assert_eq!(Bochvar::or(unknown, Bal3::from(true)), Bal3::Zero);
+//! ```
+
+use super::{Bal3, TryFromBal3Error};
+
+pub trait Logic {
+    fn not(a: Bal3) -> Bal3 {
+        -a
+    }
+
+    fn and(a: Bal3, b: Bal3) -> Bal3;
+
+    fn or(a: Bal3, b: Bal3) -> Bal3;
+
+    fn implies(a: Bal3, b: Bal3) -> Bal3;
+
+    /// Implication both ways.
+    fn equiv(a: Bal3, b: Bal3) -> Bal3 {
+        Self::and(Self::implies(a, b), Self::implies(b, a))
+    }
+}
+
+/// Kleene's strong logic: and is the minimum, or the maximum, and unknown
+/// only spreads when the known operand doesn't settle the answer.
+pub struct Kleene;
+
+impl Logic for Kleene {
+    fn and(a: Bal3, b: Bal3) -> Bal3 {
+        a.min(b)
+    }
+
+    fn or(a: Bal3, b: Bal3) -> Bal3 {
+        a.max(b)
+    }
+
+    fn implies(a: Bal3, b: Bal3) -> Bal3 {
+        Self::or(Self::not(a), b)
+    }
+}
+
+/// Łukasiewicz's logic, which is Kleene's except that unknown implies
+/// unknown is true, so equivalence is `1 - |a - b|`.
+pub struct Lukasiewicz;
+
+impl Logic for Lukasiewicz {
+    fn and(a: Bal3, b: Bal3) -> Bal3 {
+        Kleene::and(a, b)
+    }
+
+    fn or(a: Bal3, b: Bal3) -> Bal3 {
+        Kleene::or(a, b)
+    }
+
+    /// `min(1, 1 - a + b)`
+    fn implies(a: Bal3, b: Bal3) -> Bal3 {
+        Bal3::from_sign(1 - i8::from(a) + i8::from(b))
+    }
+}
+
+/// Bochvar's internal (weak) logic: any unknown operand makes the result
+/// unknown, otherwise it's classical logic.
+pub struct Bochvar;
+
+impl Bochvar {
+    fn infect(a: Bal3, b: Bal3, op: fn(Bal3, Bal3) -> Bal3) -> Bal3 {
+        match (a, b) {
+            (Bal3::Zero, _) | (_, Bal3::Zero) => Bal3::Zero,
+            _ => op(a, b),
+        }
+    }
+}
+
+impl Logic for Bochvar {
+    fn and(a: Bal3, b: Bal3) -> Bal3 {
+        Self::infect(a, b, Kleene::and)
+    }
+
+    fn or(a: Bal3, b: Bal3) -> Bal3 {
+        Self::infect(a, b, Kleene::or)
+    }
+
+    fn implies(a: Bal3, b: Bal3) -> Bal3 {
+        Self::infect(a, b, Kleene::implies)
+    }
+}
+
+/// The results of `f` for every pair of trits, indexed `[a][b]` in the
+/// order `T`, `0`, `1`.
+pub fn truth_table(f: impl Fn(Bal3, Bal3) -> Bal3) -> [[Bal3; 3]; 3] {
+    const TRITS: [Bal3; 3] = [Bal3::NegativeOne, Bal3::Zero, Bal3::One];
+    TRITS.map(|a| TRITS.map(|b| f(a, b)))
+}
+
+impl From<bool> for Bal3 {
+    fn from(input: bool) -> Self {
+        match input {
+            true => Bal3::One,
+            false => Bal3::NegativeOne,
+        }
+    }
+}
+
+/// `None` is unknown.
+impl From<Option<bool>> for Bal3 {
+    fn from(input: Option<bool>) -> Self {
+        input.map_or(Bal3::Zero, Bal3::from)
+    }
+}
+
+impl TryFrom<Bal3> for bool {
+    type Error = TryFromBal3Error;
+
+    fn try_from(input: Bal3) -> Result<Self, Self::Error> {
+        match input {
+            Bal3::One => Ok(true),
+            Bal3::Zero => Err(TryFromBal3Error::Unknown),
+            Bal3::NegativeOne => Ok(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod logic_tests {
+    use super::*;
+    use Bal3::{One, Zero, NegativeOne};
+
+    #[test]
+    fn kleene() {
+        assert_eq!(truth_table(Kleene::and), [[NegativeOne; 3], [NegativeOne, Zero, Zero], [NegativeOne, Zero, One]]);
+        assert_eq!(truth_table(Kleene::or), [[NegativeOne, Zero, One], [Zero, Zero, One], [One; 3]]);
+        assert_eq!(truth_table(Kleene::implies), [[One; 3], [Zero, Zero, One], [NegativeOne, Zero, One]]);
+        assert_eq!(truth_table(Kleene::equiv), [[One, Zero, NegativeOne], [Zero; 3], [NegativeOne, Zero, One]]);
+        assert_eq!(Kleene::not(Zero), Zero);
+    }
+
+    #[test]
+    fn lukasiewicz() {
+        assert_eq!(truth_table(Lukasiewicz::and), truth_table(Kleene::and));
+        assert_eq!(truth_table(Lukasiewicz::implies), [[One; 3], [Zero, One, One], [NegativeOne, Zero, One]]);
+        assert_eq!(truth_table(Lukasiewicz::equiv), [[One, Zero, NegativeOne], [Zero, One, Zero], [NegativeOne, Zero, One]]);
+    }
+
+    #[test]
+    fn bochvar() {
+        assert_eq!(truth_table(Bochvar::and), [[NegativeOne, Zero, NegativeOne], [Zero; 3], [NegativeOne, Zero, One]]);
+        assert_eq!(truth_table(Bochvar::or), [[NegativeOne, Zero, One], [Zero; 3], [One, Zero, One]]);
+        assert_eq!(truth_table(Bochvar::implies), [[One, Zero, One], [Zero; 3], [NegativeOne, Zero, One]]);
+    }
+
+    #[test]
+    fn bools() {
+        assert_eq!((Bal3::from(true), Bal3::from(false)), (One, NegativeOne));
+        assert_eq!((Bal3::from(Some(true)), Bal3::from(None)), (One, Zero));
+        assert_eq!((bool::try_from(One), bool::try_from(NegativeOne)), (Ok(true), Ok(false)));
+        assert_eq!(bool::try_from(Zero), Err(TryFromBal3Error::Unknown));
+    }
+}
@@ -0,0 +1,33 @@
+//! Everything needed to work with balanced ternary numbers in one import.
+//!
+//! ```
+//! use balanced_base::prelude::*;
+//!
+//! let six: BalTern<10> = "1T0".parse().unwrap();
+//! assert_eq!(-six + six, BalTern::ZERO);
+//! ```
+
+pub use crate::{Bal3, BalTern, BigBalTern, TryFromIntError, Width, ParseBalTernError, ArithmeticError, TryFromBal3Error, TritAlphabet};
+#[cfg(any(feature = "t3", feature = "t5", feature = "t6", feature = "t9", feature = "t10",
+    feature = "t18", feature = "t20", feature = "t27", feature = "t40", feature = "t80"))]
+pub use crate::AnyBalTern;
+#[cfg(feature = "t3")]
+pub use crate::T3;
+#[cfg(feature = "t5")]
+pub use crate::T5;
+#[cfg(feature = "t6")]
+pub use crate::T6;
+#[cfg(feature = "t9")]
+pub use crate::T9;
+#[cfg(feature = "t10")]
+pub use crate::T10;
+#[cfg(feature = "t18")]
+pub use crate::T18;
+#[cfg(feature = "t20")]
+pub use crate::T20;
+#[cfg(feature = "t27")]
+pub use crate::T27;
+#[cfg(feature = "t40")]
+pub use crate::T40;
+#[cfg(feature = "t80")]
+pub use crate::T80;
@@ -0,0 +1,55 @@
+//! Operators between two width aliases, widening the narrower operand.
+
+// `T5 + T10` is a `T10`, and so on for every ordered pair of widths
+macro_rules! impl_mixed_ops {
+    (@pair ($narrow:literal, $narrow_feature:literal), ($wide:literal, $wide_feature:literal): $($op:ident $method:ident),*) => {
+        $(
+            #[cfg(all(feature = $narrow_feature, feature = $wide_feature))]
+            impl std::ops::$op<$crate::BalTern<$wide>> for $crate::BalTern<$narrow> {
+                type Output = $crate::BalTern<$wide>;
+
+                fn $method(self, rhs: $crate::BalTern<$wide>) -> Self::Output {
+                    std::ops::$op::$method(Self::Output::from(self), rhs)
+                }
+            }
+
+            #[cfg(all(feature = $narrow_feature, feature = $wide_feature))]
+            impl std::ops::$op<$crate::BalTern<$narrow>> for $crate::BalTern<$wide> {
+                type Output = Self;
+
+                fn $method(self, rhs: $crate::BalTern<$narrow>) -> Self::Output {
+                    std::ops::$op::$method(self, Self::from(rhs))
+                }
+            }
+        )*
+    };
+    () => {};
+    (($narrow:literal, $narrow_feature:literal) $(, ($wide:literal, $wide_feature:literal))*) => {
+        $(
+            impl_mixed_ops!(@pair ($narrow, $narrow_feature), ($wide, $wide_feature): Add add, Sub sub, Mul mul, Div div, Rem rem);
+        )*
+        impl_mixed_ops!($(($wide, $wide_feature)),*);
+    };
+}
+
+impl_mixed_ops!(
+    (3, "t3"), (5, "t5"), (6, "t6"), (9, "t9"), (10, "t10"),
+    (18, "t18"), (20, "t20"), (27, "t27"), (40, "t40"), (80, "t80")
+);
+
+#[cfg(all(test, feature = "t3", feature = "t5", feature = "t10", feature = "t20", feature = "t40", feature = "t80"))]
+mod promote_tests {
+    use crate::{T3, T5, T10, T20, T40, T80, BigBalTern, TryFromIntError, Width};
+
+    #[test]
+    fn mixed_width_operators() {
+        let five = T5::try_from(5).unwrap();
+        let ten = T10::try_from(10).unwrap();
+        assert_eq!(five + ten, T10::try_from(15).unwrap());
+        assert_eq!(ten - five, T10::try_from(5).unwrap());
+        assert_eq!(T20::MAX_VALUE * T40::try_from(2).unwrap(), T40::try_from(2 * T20::MAX as i64).unwrap());
+        assert_eq!(T80::try_from(-100).unwrap() / T3::try_from(7).unwrap(), T80::try_from(-14).unwrap());
+        assert_eq!(T3::try_from(-100).err(), Some(TryFromIntError::NegOverflow {width: Width::Trits(3), value: BigBalTern::from(-100)}));
+        assert_eq!(T5::MAX_VALUE % T10::try_from(100).unwrap(), T10::try_from(21).unwrap());
+    }
+}
@@ -0,0 +1,28 @@
+use super::BalTern;
+use crate::convert::impl_into_int;
+
+// contained in an i32
+pub type T18 = BalTern<18>;
+
+impl T18 {
+    pub const MAX: i32 = 193710244;
+    pub const MIN: i32 = -193710244;
+}
+
+impl_into_int!(T18; From: i32, i64, i128; TryFrom: i8, i16, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod t18_tests {
+    use super::*;
+    use crate::{TryFromIntError, Width, BigBalTern};
+
+    #[test]
+    fn limits() {
+        assert_eq!(T18::try_from(T18::MAX), Ok(T18::MAX_VALUE));
+        assert_eq!(T18::try_from(T18::MIN), Ok(T18::MIN_VALUE));
+        assert_eq!(i32::from(T18::MAX_VALUE), T18::MAX);
+        assert_eq!(T18::try_from(T18::MAX + 1), Err(TryFromIntError::PosOverflow {width: Width::Trits(18), value: BigBalTern::from(T18::MAX + 1)}));
+        assert_eq!(T18::try_from(T18::MIN - 1), Err(TryFromIntError::NegOverflow {width: Width::Trits(18), value: BigBalTern::from(T18::MIN - 1)}));
+        assert_eq!(i16::try_from(T18::MAX_VALUE), Err(TryFromIntError::PosOverflow {width: Width::Bits(16), value: BigBalTern::from(T18::MAX_VALUE)}));
+    }
+}
@@ -0,0 +1,28 @@
+use super::BalTern;
+use crate::convert::impl_into_int;
+
+// contained in an i64
+pub type T27 = BalTern<27>;
+
+impl T27 {
+    pub const MAX: i64 = 3812798742493;
+    pub const MIN: i64 = -3812798742493;
+}
+
+impl_into_int!(T27; From: i64, i128; TryFrom: i8, i16, i32, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod t27_tests {
+    use super::*;
+    use crate::{TryFromIntError, Width, BigBalTern};
+
+    #[test]
+    fn limits() {
+        assert_eq!(T27::try_from(T27::MAX), Ok(T27::MAX_VALUE));
+        assert_eq!(T27::try_from(T27::MIN), Ok(T27::MIN_VALUE));
+        assert_eq!(i64::from(T27::MAX_VALUE), T27::MAX);
+        assert_eq!(T27::try_from(T27::MAX + 1), Err(TryFromIntError::PosOverflow {width: Width::Trits(27), value: BigBalTern::from(T27::MAX + 1)}));
+        assert_eq!(T27::try_from(T27::MIN - 1), Err(TryFromIntError::NegOverflow {width: Width::Trits(27), value: BigBalTern::from(T27::MIN - 1)}));
+        assert_eq!(i32::try_from(T27::MAX_VALUE), Err(TryFromIntError::PosOverflow {width: Width::Bits(32), value: BigBalTern::from(T27::MAX_VALUE)}));
+    }
+}
@@ -0,0 +1,28 @@
+use super::BalTern;
+use crate::convert::impl_into_int;
+
+// contained in an i8
+pub type T3 = BalTern<3>;
+
+impl T3 {
+    pub const MAX: i8 = 13;
+    pub const MIN: i8 = -13;
+}
+
+impl_into_int!(T3; From: i8, i16, i32, i64, i128, isize; TryFrom: u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod t3_tests {
+    use super::*;
+    use crate::{TryFromIntError, Width, BigBalTern};
+
+    #[test]
+    fn limits() {
+        assert_eq!(T3::try_from(T3::MAX), Ok(T3::MAX_VALUE));
+        assert_eq!(T3::try_from(T3::MIN), Ok(T3::MIN_VALUE));
+        assert_eq!(i8::from(T3::MAX_VALUE), T3::MAX);
+        assert_eq!(T3::try_from(T3::MAX + 1), Err(TryFromIntError::PosOverflow {width: Width::Trits(3), value: BigBalTern::from(T3::MAX + 1)}));
+        assert_eq!(T3::try_from(T3::MIN - 1), Err(TryFromIntError::NegOverflow {width: Width::Trits(3), value: BigBalTern::from(T3::MIN - 1)}));
+        assert_eq!(u128::try_from(T3::MIN_VALUE), Err(TryFromIntError::NegOverflow {width: Width::Bits(128), value: BigBalTern::from(T3::MIN_VALUE)}));
+    }
+}
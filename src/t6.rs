@@ -0,0 +1,28 @@
+use super::BalTern;
+use crate::convert::impl_into_int;
+
+// contained in an i16
+pub type T6 = BalTern<6>;
+
+impl T6 {
+    pub const MAX: i16 = 364;
+    pub const MIN: i16 = -364;
+}
+
+impl_into_int!(T6; From: i16, i32, i64, i128, isize; TryFrom: i8, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod t6_tests {
+    use super::*;
+    use crate::{TryFromIntError, Width, BigBalTern};
+
+    #[test]
+    fn limits() {
+        assert_eq!(T6::try_from(T6::MAX), Ok(T6::MAX_VALUE));
+        assert_eq!(T6::try_from(T6::MIN), Ok(T6::MIN_VALUE));
+        assert_eq!(i16::from(T6::MAX_VALUE), T6::MAX);
+        assert_eq!(T6::try_from(T6::MAX + 1), Err(TryFromIntError::PosOverflow {width: Width::Trits(6), value: BigBalTern::from(T6::MAX + 1)}));
+        assert_eq!(T6::try_from(T6::MIN - 1), Err(TryFromIntError::NegOverflow {width: Width::Trits(6), value: BigBalTern::from(T6::MIN - 1)}));
+        assert_eq!(i8::try_from(T6::MAX_VALUE), Err(TryFromIntError::PosOverflow {width: Width::Bits(8), value: BigBalTern::from(T6::MAX_VALUE)}));
+    }
+}
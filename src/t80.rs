@@ -0,0 +1,28 @@
+use super::BalTern;
+use crate::convert::impl_into_int;
+
+// contained in an i128
+pub type T80 = BalTern<80>;
+
+impl T80 {
+    pub const MAX: i128 = 73904414707172961658041605103191648800;
+    pub const MIN: i128 = -73904414707172961658041605103191648800;
+}
+
+impl_into_int!(T80; From: i128; TryFrom: i8, i16, i32, i64, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod t80_tests {
+    use super::*;
+    use crate::{TryFromIntError, Width, BigBalTern};
+
+    #[test]
+    fn limits() {
+        assert_eq!(T80::try_from(T80::MAX), Ok(T80::MAX_VALUE));
+        assert_eq!(T80::try_from(T80::MIN), Ok(T80::MIN_VALUE));
+        assert_eq!(i128::from(T80::MAX_VALUE), T80::MAX);
+        assert_eq!(T80::try_from(T80::MAX + 1), Err(TryFromIntError::PosOverflow {width: Width::Trits(80), value: BigBalTern::from(T80::MAX + 1)}));
+        assert_eq!(T80::try_from(T80::MIN - 1), Err(TryFromIntError::NegOverflow {width: Width::Trits(80), value: BigBalTern::from(T80::MIN - 1)}));
+        assert_eq!(i64::try_from(T80::MAX_VALUE), Err(TryFromIntError::PosOverflow {width: Width::Bits(64), value: BigBalTern::from(T80::MAX_VALUE)}));
+    }
+}
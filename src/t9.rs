@@ -0,0 +1,28 @@
+use super::BalTern;
+use crate::convert::impl_into_int;
+
+// contained in an i16
+pub type T9 = BalTern<9>;
+
+impl T9 {
+    pub const MAX: i16 = 9841;
+    pub const MIN: i16 = -9841;
+}
+
+impl_into_int!(T9; From: i16, i32, i64, i128, isize; TryFrom: i8, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod t9_tests {
+    use super::*;
+    use crate::{TryFromIntError, Width, BigBalTern};
+
+    #[test]
+    fn limits() {
+        assert_eq!(T9::try_from(T9::MAX), Ok(T9::MAX_VALUE));
+        assert_eq!(T9::try_from(T9::MIN), Ok(T9::MIN_VALUE));
+        assert_eq!(i16::from(T9::MAX_VALUE), T9::MAX);
+        assert_eq!(T9::try_from(T9::MAX + 1), Err(TryFromIntError::PosOverflow {width: Width::Trits(9), value: BigBalTern::from(T9::MAX + 1)}));
+        assert_eq!(T9::try_from(T9::MIN - 1), Err(TryFromIntError::NegOverflow {width: Width::Trits(9), value: BigBalTern::from(T9::MIN - 1)}));
+        assert_eq!(i8::try_from(T9::MAX_VALUE), Err(TryFromIntError::PosOverflow {width: Width::Bits(8), value: BigBalTern::from(T9::MAX_VALUE)}));
+    }
+}
@@ -0,0 +1,232 @@
+//! Reading and writing the individual trits of a `BalTern<N>`, and fields of
+//! trits such as the trytes inside a word.
+//!
+//! Trit indices count up from the least significant trit, so index `i` is
+//! the coefficient of 3^i. Arrays and iterators are most significant first,
+//! the same order the number is written in.
+
+use std::ops::{Index, IndexMut};
+use super::{Bal3, BalTern};
+
+impl<const N: usize> BalTern<N> {
+    pub const fn from_trits(value: [Bal3; N]) -> Self {
+        BalTern {value}
+    }
+
+    pub const fn to_trits(self) -> [Bal3; N] {
+        self.value
+    }
+
+    /// The coefficient of 3^`index`. Panics if `index >= N`.
+    pub fn trit(self, index: usize) -> Bal3 {
+        self[index]
+    }
+
+    /// Overwrites the coefficient of 3^`index`. Panics if `index >= N`.
+    pub fn set_trit(&mut self, index: usize, trit: Bal3) {
+        self[index] = trit;
+    }
+
+    /// A copy with the coefficient of 3^`index` replaced.
+    pub fn with_trit(mut self, index: usize, trit: Bal3) -> Self {
+        self[index] = trit;
+        self
+    }
+
+    /// Iterates most significant trit first.
+    pub fn iter(&self) -> std::iter::Copied<std::slice::Iter<'_, Bal3>> {
+        self.value.iter().copied()
+    }
+
+    /// Iterates least significant trit first, in index order.
+    pub fn iter_lsb_first(&self) -> std::iter::Rev<std::iter::Copied<std::slice::Iter<'_, Bal3>>> {
+        self.iter().rev()
+    }
+
+    /// Reads the `M` trits starting at index `start`, so `start` becomes
+    /// index 0 of the result. Panics if the field runs past the top.
+    pub fn extract<const M: usize>(self, start: usize) -> BalTern<M> {
+        assert!(start + M <= N, "a {} trit field at {} doesn't fit in a {} trit number", M, start, N);
+        let mut value = [Bal3::Zero; M];
+        value.copy_from_slice(&self.value[N - start - M..N - start]);
+        BalTern {value}
+    }
+
+    /// Overwrites the `M` trits starting at index `start` with `field`.
+    /// Panics if the field runs past the top.
+    pub fn insert<const M: usize>(&mut self, start: usize, field: BalTern<M>) {
+        assert!(start + M <= N, "a {} trit field at {} doesn't fit in a {} trit number", M, start, N);
+        self.value[N - start - M..N - start].copy_from_slice(&field.value);
+    }
+
+    /// Cuts the number into `K` words of `M` trits, most significant first.
+    pub fn split<const M: usize, const K: usize>(self) -> [BalTern<M>; K] {
+        const { assert!(M * K == N, "the words must exactly cover the number") };
+        let mut words = [BalTern::<M>::ZERO; K];
+        for (word, chunk) in words.iter_mut().zip(self.value.chunks_exact(M)) {
+            word.value.copy_from_slice(chunk);
+        }
+        words
+    }
+
+    /// Concatenates `K` words of `M` trits, most significant first.
+    pub fn join<const M: usize, const K: usize>(words: [BalTern<M>; K]) -> Self {
+        const { assert!(M * K == N, "the words must exactly cover the number") };
+        let mut value = [Bal3::Zero; N];
+        for (chunk, word) in value.chunks_exact_mut(M).zip(words) {
+            chunk.copy_from_slice(&word.value);
+        }
+        BalTern {value}
+    }
+
+    fn position(index: usize) -> usize {
+        assert!(index < N, "trit index {} out of range for a {} trit number", index, N);
+        N - 1 - index
+    }
+}
+
+impl<const N: usize> Index<usize> for BalTern<N> {
+    type Output = Bal3;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.value[Self::position(index)]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for BalTern<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.value[Self::position(index)]
+    }
+}
+
+impl<const N: usize> From<[Bal3; N]> for BalTern<N> {
+    fn from(value: [Bal3; N]) -> Self {
+        BalTern {value}
+    }
+}
+
+impl<const N: usize> From<BalTern<N>> for [Bal3; N] {
+    fn from(input: BalTern<N>) -> Self {
+        input.value
+    }
+}
+
+impl<const N: usize> IntoIterator for BalTern<N> {
+    type Item = Bal3;
+    type IntoIter = std::array::IntoIter<Bal3, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.value.into_iter()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a BalTern<N> {
+    type Item = Bal3;
+    type IntoIter = std::iter::Copied<std::slice::Iter<'a, Bal3>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Collects trits most significant first, the order they are written in.
+/// Fewer than N trits are padded with leading zeros. Panics if there are more
+/// than N and any of the extra leading trits isn't zero.
+impl<const N: usize> FromIterator<Bal3> for BalTern<N> {
+    fn from_iter<I: IntoIterator<Item = Bal3>>(iter: I) -> Self {
+        let trits: Vec<Bal3> = iter.into_iter().collect();
+        let significant = trits.iter().position(|x| *x != Bal3::Zero).map_or(0, |start| trits.len() - start);
+        assert!(significant <= N, "{} significant trits don't fit in a {} trit number", significant, N);
+        let kept = &trits[trits.len().saturating_sub(N)..];
+        let mut value = [Bal3::Zero; N];
+        value[N - kept.len()..].copy_from_slice(kept);
+        BalTern {value}
+    }
+}
+
+#[cfg(test)]
+mod trits_tests {
+    use super::*;
+    use Bal3::{One, Zero, NegativeOne};
+
+    #[test]
+    fn get_and_set() {
+        let mut x: BalTern<5> = "1T0".parse().unwrap();
+        assert_eq!((x.trit(0), x.trit(1), x.trit(2), x.trit(3)), (Zero, NegativeOne, One, Zero));
+        assert_eq!(x[1], NegativeOne);
+        x.set_trit(4, NegativeOne);
+        x[0] = One;
+        assert_eq!(x.to_string(), "T01T1");
+        assert_eq!(x.with_trit(4, Zero).to_string(), "1T1");
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_range() {
+        let _ = BalTern::<5>::ZERO.trit(5);
+    }
+
+    #[test]
+    fn iteration() {
+        let x: BalTern<4> = "1T0".parse().unwrap();
+        assert_eq!(x.iter().collect::<Vec<_>>(), [Zero, One, NegativeOne, Zero]);
+        assert_eq!(x.iter_lsb_first().collect::<Vec<_>>(), [Zero, NegativeOne, One, Zero]);
+        assert_eq!(x.into_iter().rev().collect::<Vec<_>>(), [Zero, NegativeOne, One, Zero]);
+        assert_eq!((&x).into_iter().filter(|t| *t != Zero).count(), 2);
+    }
+
+    #[test]
+    fn arrays() {
+        let trits = [Zero, One, NegativeOne, Zero];
+        assert_eq!(BalTern::from_trits(trits).to_string(), "1T0");
+        assert_eq!(BalTern::from(trits).to_trits(), trits);
+        assert_eq!(<[Bal3; 4]>::from(BalTern::from_trits(trits)), trits);
+    }
+
+    #[test]
+    fn fields() {
+        let mut word: BalTern<9> = "1T0T11010".parse().unwrap();
+        assert_eq!(word.extract::<3>(0).to_string(), "10");
+        assert_eq!(word.extract::<3>(6).to_string(), "1T0");
+        assert_eq!(word.extract::<4>(2).to_string(), "T110");
+        word.insert(3, BalTern::<3>::MIN_VALUE);
+        assert_eq!(word.to_string(), "1T0TTT010");
+        word.insert(0, BalTern::<2>::ZERO);
+        assert_eq!(word.to_string(), "1T0TTT000");
+    }
+
+    #[test]
+    #[should_panic]
+    fn field_out_of_range() {
+        let _ = BalTern::<9>::ZERO.extract::<3>(7);
+    }
+
+    #[test]
+    fn split_and_join() {
+        let word: BalTern<9> = "1T0T11010".parse().unwrap();
+        let trytes: [BalTern<3>; 3] = word.split();
+        assert_eq!(trytes.map(|x| x.to_string()), ["1T0", "T11", "10"]);
+        assert_eq!(BalTern::<9>::join(trytes), word);
+        let [high, low]: [BalTern<6>; 2] = BalTern::<12>::MAX_VALUE.split();
+        assert_eq!((high, low), (BalTern::MAX_VALUE, BalTern::MAX_VALUE));
+        let words: [BalTern<5>; 8] = BalTern::<40>::try_from(-48).unwrap().split();
+        assert_eq!(words[7], BalTern::try_from(-48).unwrap());
+        assert_eq!(BalTern::<40>::join(words), BalTern::try_from(-48).unwrap());
+    }
+
+    #[test]
+    fn collect() {
+        let x: BalTern<4> = [One, NegativeOne, Zero].into_iter().collect();
+        assert_eq!(x.to_string(), "1T0");
+        let x: BalTern<2> = [Zero, Zero, NegativeOne, Zero].into_iter().collect();
+        assert_eq!(x.to_string(), "T0");
+        let x: BalTern<4> = "T101".parse::<BalTern<4>>().unwrap().iter_lsb_first().collect();
+        assert_eq!(x.to_string(), "101T");
+    }
+
+    #[test]
+    #[should_panic]
+    fn collect_too_many() {
+        let _: BalTern<3> = [One, One, One, One].into_iter().collect();
+    }
+}